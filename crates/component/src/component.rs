@@ -1,27 +1,133 @@
+//! A registry of UI components and their previews, for browsing them in a
+//! gallery.
+//!
+//! Components register themselves through [`Component`] and
+//! [`ComponentPreview`], but nothing is in the registry until [`init`] runs.
+//! Call [`init`] once at startup, before [`components`] or anything else that
+//! reads the registry. In debug builds, [`components`] panics if it's called
+//! first.
+
+pub mod export;
+
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::ops::{Deref, DerefMut};
-use std::sync::LazyLock;
+use std::panic::{AssertUnwindSafe, Location, catch_unwind};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+use std::sync::{Arc, LazyLock};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use collections::HashMap;
-use gpui::{AnyElement, App, IntoElement, RenderOnce, SharedString, Window, div, prelude::*, px};
+use collections::{HashMap, HashSet, IndexMap};
+use gpui::{
+    AnyElement, App, Bounds, Context, Div, Edges, Element, ElementId, Empty, GlobalElementId, Hsla,
+    IntoElement, LayoutId, Pixels, Render, RenderOnce, SharedString, Subscription, Window, div,
+    prelude::*, px,
+};
 use linkme::distributed_slice;
-use parking_lot::RwLock;
-use theme::ActiveTheme;
+use parking_lot::{Mutex, RwLock};
+use settings::Settings;
+use theme::{ActiveTheme, Appearance, Theme, ThemeRegistry, ThemeSelection, ThemeSettings};
 
 pub trait Component {
-    fn scope() -> Option<ComponentScope>;
+    /// The scope of the component, defaulting to the scope named by the
+    /// module it's defined in, if any. See [`ComponentScope::from_type_path`].
+    fn scope() -> Option<ComponentScope> {
+        ComponentScope::from_type_path(std::any::type_name::<Self>())
+    }
+    /// The name of the component, defaulting to its type name.
+    ///
+    /// Generic components must override this, as their type names include
+    /// their type parameters, like `Button<ThemeColors>`.
     fn name() -> &'static str {
         std::any::type_name::<Self>()
     }
     fn description() -> Option<&'static str> {
         None
     }
+    /// The current API version of the component
+    fn version() -> Option<&'static str> {
+        None
+    }
+    /// The version the component was introduced in
+    fn since() -> Option<&'static str> {
+        None
+    }
+    /// Whether the component is deprecated and shouldn't be used in new code
+    fn deprecated() -> bool {
+        false
+    }
+    /// Why the component is deprecated, or what to use instead. Providing a
+    /// message marks the component as deprecated.
+    fn deprecation_message() -> Option<&'static str> {
+        None
+    }
+    /// How mature the component is
+    fn status() -> ComponentStatus {
+        ComponentStatus::Stable
+    }
+    /// Keywords for categories the component belongs to beyond its scope
+    fn tags() -> &'static [&'static str] {
+        &[]
+    }
+    /// Extra words the component should be found by when searching
+    fn keywords() -> &'static [&'static str] {
+        &[]
+    }
+    /// Alternate names the component is known by, like "Dropdown" for a
+    /// select
+    fn aliases() -> &'static [&'static str] {
+        &[]
+    }
+    /// Where the component is defined. Override this with
+    /// [`component_source!`] inside the `impl`.
+    fn source_location() -> Option<&'static Location<'static>> {
+        None
+    }
+    /// The names of components worth looking at alongside this one
+    fn related() -> &'static [&'static str] {
+        &[]
+    }
+    /// The platforms the component renders correctly on. Empty means every
+    /// platform.
+    fn platforms() -> &'static [Platform] {
+        &[]
+    }
+    /// A human-readable label for screen readers and accessibility audits
+    fn accessibility_label() -> Option<&'static str> {
+        None
+    }
 }
 
 pub trait ComponentPreview: Component {
     fn preview(_window: &mut Window, _cx: &mut App) -> AnyElement;
+    /// Renders the component in each of its states, like "Hover" or
+    /// "Disabled", along with the name of each state
+    fn preview_variants(window: &mut Window, cx: &mut App) -> Vec<(&'static str, AnyElement)> {
+        vec![(DEFAULT_PREVIEW_LABEL, Self::preview(window, cx))]
+    }
+    /// The width and height the preview should be shown at, if it can't size
+    /// itself
+    fn preview_size() -> Option<(f32, f32)> {
+        None
+    }
+    /// Additional named previews, shown after [`ComponentPreview::preview`]
+    fn previews() -> Vec<(&'static str, ComponentPreviewFn)> {
+        Vec::new()
+    }
 }
 
+/// Renders a preview of a component.
+pub type ComponentPreviewFn = fn(&mut Window, &mut App) -> AnyElement;
+
+/// Renders a component in each of its states.
+pub type ComponentPreviewVariantsFn = fn(&mut Window, &mut App) -> Vec<(&'static str, AnyElement)>;
+
+/// The label of the preview registered through [`ComponentPreview::preview`].
+const DEFAULT_PREVIEW_LABEL: &str = "Default";
+
 #[distributed_slice]
 pub static __ALL_COMPONENTS: [fn()] = [..];
 
@@ -31,20 +137,232 @@ pub static __ALL_PREVIEWS: [fn()] = [..];
 pub static COMPONENT_DATA: LazyLock<RwLock<ComponentRegistry>> =
     LazyLock::new(|| RwLock::new(ComponentRegistry::new()));
 
+type RegisterObserver = Arc<dyn Fn(&ComponentId) + Send + Sync>;
+
+/// The callbacks added with [`on_register`], keyed by the id their
+/// subscription removes them by.
+static REGISTER_OBSERVERS: LazyLock<RwLock<Vec<(usize, RegisterObserver)>>> =
+    LazyLock::new(|| RwLock::new(Vec::new()));
+
+static NEXT_REGISTER_OBSERVER_ID: AtomicUsize = AtomicUsize::new(0);
+
 pub struct ComponentRegistry {
-    components: Vec<(Option<ComponentScope>, &'static str, Option<&'static str>)>,
-    previews: HashMap<&'static str, fn(&mut Window, &mut App) -> AnyElement>,
+    /// Components keyed by name, in registration order. Their previews are
+    /// stored separately, so they can be registered independently.
+    components: IndexMap<SharedString, ComponentMetadata>,
+    /// Names that were registered more than once.
+    duplicate_names: Vec<SharedString>,
+    /// Previews of each component keyed by component name, in the order they
+    /// should be shown. The default preview comes first.
+    previews: HashMap<SharedString, Vec<(&'static str, ComponentPreviewFn)>>,
+    /// [`ComponentPreview::preview_variants`] of each component keyed by
+    /// component name.
+    preview_variants: HashMap<SharedString, ComponentPreviewVariantsFn>,
+    /// [`ComponentPreview::preview_size`] of each component that has one,
+    /// keyed by component name.
+    preview_sizes: HashMap<SharedString, (f32, f32)>,
+    /// Incremented whenever a component or preview is registered or removed.
+    generation: u64,
+    /// The last result of [`components`] along with the generation it was
+    /// built at.
+    cached_components: Mutex<Option<(u64, Arc<AllComponents>)>>,
+    /// Whether [`init`] has run.
+    initialized: bool,
 }
 
 impl ComponentRegistry {
     fn new() -> Self {
         ComponentRegistry {
-            components: Vec::new(),
+            components: IndexMap::default(),
+            duplicate_names: Vec::new(),
             previews: HashMap::default(),
+            preview_variants: HashMap::default(),
+            preview_sizes: HashMap::default(),
+            generation: 0,
+            cached_components: Mutex::new(None),
+            initialized: false,
+        }
+    }
+
+    fn build_components(&self) -> AllComponents {
+        let mut all_components = AllComponents::new();
+
+        for component in self.components.values() {
+            let metadata = self.metadata(component);
+            all_components.insert(metadata.id(), metadata);
+        }
+
+        all_components
+    }
+
+    /// Removes all registered components and previews.
+    pub fn clear(&mut self) {
+        self.components.clear();
+        self.duplicate_names.clear();
+        self.previews.clear();
+        self.preview_variants.clear();
+        self.preview_sizes.clear();
+        self.generation += 1;
+    }
+
+    /// Returns the number of registered components.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns whether no components are registered.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Returns the metadata of the component registered under the given name
+    pub fn get_by_name(&self, name: &str) -> Option<ComponentMetadata> {
+        let component = self.components.get(name)?;
+        Some(self.metadata(component))
+    }
+
+    /// Returns the names of the registered components, to compare with later
+    /// registry states using [`RegistrySnapshot::diff`].
+    pub fn snapshot(&self) -> RegistrySnapshot {
+        let mut names: Vec<SharedString> = self.components.keys().cloned().collect();
+        names.sort_unstable();
+        RegistrySnapshot { names }
+    }
+
+    /// Adds a component, moving its previews into the preview maps.
+    fn insert(&mut self, mut component: ComponentMetadata) {
+        let name = component.name.clone();
+        if !component.previews.is_empty() {
+            self.previews
+                .insert(name.clone(), std::mem::take(&mut component.previews));
+        }
+        if let Some(preview_variants) = component.preview_variants.take() {
+            self.preview_variants.insert(name.clone(), preview_variants);
+        }
+        if let Some(preview_size) = component.preview_size.take() {
+            self.preview_sizes.insert(name.clone(), preview_size);
+        }
+        if self.components.insert(name.clone(), component).is_some() {
+            log::warn!(
+                "component {:?} is registered more than once, only the last registration will be used",
+                name
+            );
+            self.duplicate_names.push(name);
+        }
+        self.generation += 1;
+    }
+
+    /// Removes everything registered for the preview of the given component,
+    /// returning whether anything was registered.
+    fn remove_preview(&mut self, name: &str) -> bool {
+        let removed_previews = self.previews.remove(name).is_some();
+        let removed_variants = self.preview_variants.remove(name).is_some();
+        let removed_size = self.preview_sizes.remove(name).is_some();
+        removed_previews || removed_variants || removed_size
+    }
+
+    fn metadata(&self, component: &ComponentMetadata) -> ComponentMetadata {
+        let mut metadata = component.clone();
+        metadata.previews = self
+            .previews
+            .get(&component.name)
+            .cloned()
+            .unwrap_or_default();
+        metadata.preview_variants = self.preview_variants.get(&component.name).copied();
+        metadata.preview_size = self.preview_sizes.get(&component.name).copied();
+        metadata
+    }
+}
+
+/// Implements [`Component::source_location`] as the place the macro is used.
+///
+/// ```ignore
+/// impl Component for Button {
+///     component::component_source!();
+///     // ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! component_source {
+    () => {
+        fn source_location() -> Option<&'static std::panic::Location<'static>> {
+            Some(std::panic::Location::caller())
+        }
+    };
+}
+
+/// Registers a component when [`init`] is called. The calling crate must
+/// depend on `linkme`.
+///
+/// ```ignore
+/// component::component!(Button);
+/// ```
+#[macro_export]
+macro_rules! component {
+    ($component:ty) => {
+        const _: () = {
+            #[linkme::distributed_slice($crate::__ALL_COMPONENTS)]
+            fn register() {
+                $crate::register_component::<$component>();
+            }
+        };
+    };
+}
+
+/// Registers the preview of a component when [`init`] is called. The calling
+/// crate must depend on `linkme`.
+///
+/// ```ignore
+/// component::component_preview!(Button);
+/// ```
+#[macro_export]
+macro_rules! component_preview {
+    ($component:ty) => {
+        const _: () = {
+            #[linkme::distributed_slice($crate::__ALL_PREVIEWS)]
+            fn register() {
+                $crate::register_preview::<$component>();
+            }
+        };
+    };
+}
+
+/// The names of the components registered at some point, sorted by name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistrySnapshot {
+    pub names: Vec<SharedString>,
+}
+
+impl RegistrySnapshot {
+    /// Returns the components added and removed between two snapshots
+    pub fn diff(before: &RegistrySnapshot, after: &RegistrySnapshot) -> RegistryDiff {
+        let before_names: HashSet<&SharedString> = before.names.iter().collect();
+        let after_names: HashSet<&SharedString> = after.names.iter().collect();
+        RegistryDiff {
+            added: after
+                .names
+                .iter()
+                .filter(|name| !before_names.contains(name))
+                .cloned()
+                .collect(),
+            removed: before
+                .names
+                .iter()
+                .filter(|name| !after_names.contains(name))
+                .cloned()
+                .collect(),
         }
     }
 }
 
+/// The components added and removed between two [`RegistrySnapshot`]s, sorted
+/// by name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryDiff {
+    pub added: Vec<SharedString>,
+    pub removed: Vec<SharedString>,
+}
+
 pub fn init() {
     let component_fns: Vec<_> = __ALL_COMPONENTS.iter().cloned().collect();
     let preview_fns: Vec<_> = __ALL_PREVIEWS.iter().cloned().collect();
@@ -55,306 +373,2250 @@ pub fn init() {
     for f in preview_fns {
         f();
     }
+    COMPONENT_DATA.write().initialized = true;
 }
 
 pub fn register_component<T: Component>() {
-    let component_data = (T::scope(), T::name(), T::description());
-    COMPONENT_DATA.write().components.push(component_data);
+    debug_assert!(
+        !T::name().contains(['<', '>']),
+        "component name {:?} contains type parameters, override `Component::name`",
+        T::name()
+    );
+    let deprecated = T::deprecated()
+        || T::deprecation_message().is_some()
+        || T::status() == ComponentStatus::Deprecated;
+    let component = ComponentMetadata {
+        id: ComponentId::new_static(T::name()),
+        name: SharedString::new_static(T::name()),
+        scope: T::scope(),
+        description: T::description().map(Into::into),
+        version: T::version().map(Into::into),
+        since: T::since().map(Into::into),
+        deprecated,
+        deprecation_message: T::deprecation_message().map(Into::into),
+        status: if deprecated {
+            ComponentStatus::Deprecated
+        } else {
+            T::status()
+        },
+        tags: dedup_case_insensitive(T::tags().iter().copied()),
+        keywords: dedup_case_insensitive(T::keywords().iter().copied()),
+        aliases: dedup_case_insensitive(T::aliases().iter().copied()),
+        source_location: T::source_location(),
+        related: T::related().to_vec(),
+        platforms: T::platforms().to_vec(),
+        accessibility_label: T::accessibility_label().map(Into::into),
+        previews: Vec::new(),
+        preview_variants: None,
+        preview_size: None,
+    };
+    let id = component.id();
+    COMPONENT_DATA.write().insert(component);
+    notify_registered(&id);
+}
+
+/// Registers a component built at runtime rather than from a [`Component`]
+/// implementation, along with any previews it has.
+pub fn register_component_dynamic(metadata: ComponentMetadata) {
+    let id = metadata.id();
+    COMPONENT_DATA.write().insert(metadata);
+    notify_registered(&id);
+}
+
+/// Calls `f` with the id of every component registered from now on, after
+/// it's been added to the registry, until the returned subscription is
+/// dropped.
+pub fn on_register(f: impl Fn(&ComponentId) + Send + Sync + 'static) -> Subscription {
+    let observer_id = NEXT_REGISTER_OBSERVER_ID.fetch_add(1, SeqCst);
+    REGISTER_OBSERVERS.write().push((observer_id, Arc::new(f)));
+    Subscription::new(move || {
+        REGISTER_OBSERVERS
+            .write()
+            .retain(|(id, _)| *id != observer_id);
+    })
+}
+
+fn notify_registered(id: &ComponentId) {
+    // Observers are called without holding any lock, so they can read the
+    // registry or add more observers.
+    let observers = REGISTER_OBSERVERS.read().clone();
+    for (_, observer) in observers {
+        observer(id);
+    }
 }
 
 pub fn register_preview<T: ComponentPreview>() {
-    let preview_data = (
-        T::name(),
-        T::preview as fn(&mut Window, &mut App) -> AnyElement,
+    let mut data = COMPONENT_DATA.write();
+    let name = SharedString::new_static(T::name());
+    let previews = data.previews.entry(name.clone()).or_default();
+    previews.retain(|(label, _)| *label != DEFAULT_PREVIEW_LABEL);
+    previews.insert(0, (DEFAULT_PREVIEW_LABEL, T::preview as ComponentPreviewFn));
+    for (label, preview) in T::previews() {
+        insert_preview(previews, label, preview);
+    }
+    data.preview_variants.insert(
+        name.clone(),
+        T::preview_variants as ComponentPreviewVariantsFn,
     );
-    COMPONENT_DATA
-        .write()
+    match T::preview_size() {
+        Some(size) => data.preview_sizes.insert(name, size),
+        None => data.preview_sizes.remove(&name),
+    };
+    data.generation += 1;
+}
+
+/// Registers an additional preview of a component under the given label,
+/// replacing any preview previously registered under that label.
+pub fn register_preview_variant<T: Component>(label: &'static str, preview: ComponentPreviewFn) {
+    let mut data = COMPONENT_DATA.write();
+    let previews = data
         .previews
-        .insert(preview_data.0, preview_data.1);
+        .entry(SharedString::new_static(T::name()))
+        .or_default();
+    insert_preview(previews, label, preview);
+    data.generation += 1;
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct ComponentId(pub &'static str);
+/// Adds a preview under the given label, replacing any preview previously
+/// added under that label.
+fn insert_preview(
+    previews: &mut Vec<(&'static str, ComponentPreviewFn)>,
+    label: &'static str,
+    preview: ComponentPreviewFn,
+) {
+    match previews.iter_mut().find(|(existing, _)| *existing == label) {
+        Some(existing) => existing.1 = preview,
+        None => previews.push((label, preview)),
+    }
+}
 
-#[derive(Clone)]
-pub struct ComponentMetadata {
-    id: ComponentId,
-    name: SharedString,
-    scope: Option<ComponentScope>,
-    description: Option<SharedString>,
-    preview: Option<fn(&mut Window, &mut App) -> AnyElement>,
+/// Returns the given strings without case-insensitive duplicates, keeping the
+/// first occurrence of each.
+fn dedup_case_insensitive(
+    strings: impl IntoIterator<Item = impl Into<SharedString>>,
+) -> Vec<SharedString> {
+    let mut seen = HashSet::default();
+    strings
+        .into_iter()
+        .map(Into::into)
+        .filter(|string| seen.insert(string.to_lowercase()))
+        .collect()
 }
 
-impl ComponentMetadata {
-    pub fn id(&self) -> ComponentId {
-        self.id.clone()
-    }
+/// Returns the names registered by more than one component, sorted by name.
+/// Only the last registration of each name is included in [`components`].
+pub fn duplicate_names() -> Vec<SharedString> {
+    let mut duplicates = COMPONENT_DATA.read().duplicate_names.clone();
+    duplicates.sort_unstable();
+    duplicates.dedup();
+    duplicates
+}
 
-    pub fn name(&self) -> SharedString {
-        self.name.clone()
-    }
+/// Returns a counter that changes whenever components or previews are
+/// registered or removed, so consumers can tell when to rebuild views derived
+/// from [`components`].
+pub fn registry_generation() -> u64 {
+    COMPONENT_DATA.read().generation
+}
 
-    pub fn scope(&self) -> Option<ComponentScope> {
-        self.scope.clone()
-    }
+/// Removes all registered components and previews. The registry counts as
+/// initialized afterwards, so tests can register components by hand without
+/// calling [`init`].
+#[cfg(any(test, feature = "test-support"))]
+pub fn reset_registry() {
+    let mut data = COMPONENT_DATA.write();
+    data.clear();
+    data.initialized = true;
+}
 
-    pub fn description(&self) -> Option<SharedString> {
-        self.description.clone()
+/// Removes a registered component along with its preview. Does nothing if the
+/// component isn't registered.
+pub fn deregister_component(id: &ComponentId) {
+    let mut data = COMPONENT_DATA.write();
+    let removed_component = data.components.shift_remove(id.as_str()).is_some();
+    let removed_preview = data.remove_preview(id.as_str());
+    if removed_component || removed_preview {
+        data.generation += 1;
     }
+}
 
-    pub fn preview(&self) -> Option<fn(&mut Window, &mut App) -> AnyElement> {
-        self.preview
+/// Removes a registered preview. Does nothing if the preview isn't registered.
+pub fn deregister_preview(id: &ComponentId) {
+    let mut data = COMPONENT_DATA.write();
+    if data.remove_preview(id.as_str()) {
+        data.generation += 1;
     }
 }
 
-pub struct AllComponents(pub HashMap<ComponentId, ComponentMetadata>);
-
-impl AllComponents {
-    pub fn new() -> Self {
-        AllComponents(HashMap::default())
-    }
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ComponentId(pub SharedString);
 
-    /// Returns all components with previews
-    pub fn all_previews(&self) -> Vec<&ComponentMetadata> {
-        self.0.values().filter(|c| c.preview.is_some()).collect()
+impl ComponentId {
+    pub fn new(id: impl Into<SharedString>) -> Self {
+        Self(id.into())
     }
 
-    /// Returns all components with previews sorted by name
-    pub fn all_previews_sorted(&self) -> Vec<ComponentMetadata> {
-        let mut previews: Vec<ComponentMetadata> =
-            self.all_previews().into_iter().cloned().collect();
-        previews.sort_by_key(|a| a.name());
-        previews
+    pub const fn new_static(id: &'static str) -> Self {
+        Self(SharedString::new_static(id))
     }
 
-    /// Returns all components
-    pub fn all(&self) -> Vec<&ComponentMetadata> {
-        self.0.values().collect()
+    pub fn as_str(&self) -> &str {
+        &self.0
     }
 
-    /// Returns all components sorted by name
-    pub fn all_sorted(&self) -> Vec<ComponentMetadata> {
-        let mut components: Vec<ComponentMetadata> = self.all().into_iter().cloned().collect();
-        components.sort_by_key(|a| a.name());
-        components
+    /// Returns the id without its module path, so `ui::button::Button`
+    /// becomes `Button`. Generic arguments are kept as they are.
+    pub fn short_name(&self) -> &str {
+        short_type_name(&self.0)
     }
 }
 
-impl Deref for AllComponents {
-    type Target = HashMap<ComponentId, ComponentMetadata>;
+fn short_type_name(path: &str) -> &str {
+    let base_len = path.find('<').unwrap_or(path.len());
+    let start = path[..base_len].rfind("::").map_or(0, |index| index + 2);
+    &path[start..]
+}
 
-    fn deref(&self) -> &Self::Target {
+impl AsRef<str> for ComponentId {
+    fn as_ref(&self) -> &str {
         &self.0
     }
 }
 
-impl DerefMut for AllComponents {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+impl Display for ComponentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
-pub fn components() -> AllComponents {
-    let data = COMPONENT_DATA.read();
-    let mut all_components = AllComponents::new();
-
-    for (scope, name, description) in &data.components {
-        let preview = data.previews.get(name).cloned();
-        let component_name = SharedString::new_static(name);
-        let id = ComponentId(name);
-        all_components.insert(
-            id.clone(),
-            ComponentMetadata {
-                id,
-                name: component_name,
-                scope: scope.clone(),
-                description: description.map(Into::into),
-                preview,
-            },
-        );
+impl From<ComponentId> for String {
+    fn from(id: ComponentId) -> Self {
+        id.0.to_string()
     }
-
-    all_components
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ComponentScope {
-    Layout,
-    Input,
-    Notification,
-    Editor,
-    Collaboration,
-    VersionControl,
-    Unknown(SharedString),
+#[derive(Clone)]
+pub struct ComponentMetadata {
+    id: ComponentId,
+    name: SharedString,
+    scope: Option<ComponentScope>,
+    description: Option<SharedString>,
+    version: Option<SharedString>,
+    since: Option<SharedString>,
+    deprecated: bool,
+    deprecation_message: Option<SharedString>,
+    status: ComponentStatus,
+    tags: Vec<SharedString>,
+    keywords: Vec<SharedString>,
+    aliases: Vec<SharedString>,
+    source_location: Option<&'static Location<'static>>,
+    related: Vec<&'static str>,
+    platforms: Vec<Platform>,
+    accessibility_label: Option<SharedString>,
+    previews: Vec<(&'static str, ComponentPreviewFn)>,
+    preview_variants: Option<ComponentPreviewVariantsFn>,
+    preview_size: Option<(f32, f32)>,
 }
 
-impl Display for ComponentScope {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ComponentScope::Layout => write!(f, "Layout"),
-            ComponentScope::Input => write!(f, "Input"),
-            ComponentScope::Notification => write!(f, "Notification"),
-            ComponentScope::Editor => write!(f, "Editor"),
-            ComponentScope::Collaboration => write!(f, "Collaboration"),
-            ComponentScope::VersionControl => write!(f, "Version Control"),
-            ComponentScope::Unknown(name) => write!(f, "Unknown: {}", name),
-        }
+/// Previews are compared by their labels and whether variants are registered,
+/// not by the functions that render them.
+impl PartialEq for ComponentMetadata {
+    fn eq(&self, other: &Self) -> bool {
+        let preview_labels = |metadata: &Self| {
+            metadata
+                .previews
+                .iter()
+                .map(|(label, _)| *label)
+                .collect::<Vec<_>>()
+        };
+        let preview_size_bits = |metadata: &Self| {
+            metadata
+                .preview_size
+                .map(|(width, height)| (width.to_bits(), height.to_bits()))
+        };
+
+        self.id == other.id
+            && self.name == other.name
+            && self.scope == other.scope
+            && self.description == other.description
+            && self.version == other.version
+            && self.since == other.since
+            && self.deprecated == other.deprecated
+            && self.deprecation_message == other.deprecation_message
+            && self.status == other.status
+            && self.tags == other.tags
+            && self.keywords == other.keywords
+            && self.aliases == other.aliases
+            && self.source_location == other.source_location
+            && self.related == other.related
+            && self.platforms == other.platforms
+            && self.accessibility_label == other.accessibility_label
+            && preview_labels(self) == preview_labels(other)
+            && self.preview_variants.is_some() == other.preview_variants.is_some()
+            && preview_size_bits(self) == preview_size_bits(other)
     }
 }
 
-impl From<&str> for ComponentScope {
-    fn from(value: &str) -> Self {
-        match value {
-            "Layout" => ComponentScope::Layout,
-            "Input" => ComponentScope::Input,
-            "Notification" => ComponentScope::Notification,
-            "Editor" => ComponentScope::Editor,
-            "Collaboration" => ComponentScope::Collaboration,
-            "Version Control" | "VersionControl" => ComponentScope::VersionControl,
-            _ => ComponentScope::Unknown(SharedString::new(value)),
+impl Eq for ComponentMetadata {}
+
+impl ComponentMetadata {
+    /// Creates the metadata of a component with nothing but a name, which is
+    /// also used as its id, to be registered with
+    /// [`register_component_dynamic`].
+    pub fn new(name: impl Into<SharedString>) -> Self {
+        let name = name.into();
+        ComponentMetadata {
+            id: ComponentId(name.clone()),
+            name,
+            scope: None,
+            description: None,
+            version: None,
+            since: None,
+            deprecated: false,
+            deprecation_message: None,
+            status: ComponentStatus::Stable,
+            tags: Vec::new(),
+            keywords: Vec::new(),
+            aliases: Vec::new(),
+            source_location: None,
+            related: Vec::new(),
+            platforms: Vec::new(),
+            accessibility_label: None,
+            previews: Vec::new(),
+            preview_variants: None,
+            preview_size: None,
         }
     }
-}
 
-impl From<String> for ComponentScope {
-    fn from(value: String) -> Self {
-        match value.as_str() {
-            "Layout" => ComponentScope::Layout,
-            "Input" => ComponentScope::Input,
-            "Notification" => ComponentScope::Notification,
-            "Editor" => ComponentScope::Editor,
-            "Collaboration" => ComponentScope::Collaboration,
-            "Version Control" | "VersionControl" => ComponentScope::VersionControl,
-            _ => ComponentScope::Unknown(SharedString::new(value)),
-        }
+    /// Sets the scope of the component.
+    pub fn with_scope(mut self, scope: ComponentScope) -> Self {
+        self.scope = Some(scope);
+        self
     }
-}
 
-/// Which side of the preview to show labels on
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ExampleLabelSide {
-    /// Left side
-    Left,
-    /// Right side
-    Right,
-    /// Top side
-    #[default]
-    Top,
-    /// Bottom side
-    Bottom,
-}
+    /// Sets the description of the component.
+    pub fn with_description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
 
-/// A single example of a component.
-#[derive(IntoElement)]
-pub struct ComponentExample {
-    variant_name: SharedString,
-    element: AnyElement,
-    label_side: ExampleLabelSide,
-    grow: bool,
-}
+    /// Sets the label read out by screen readers. See
+    /// [`Component::accessibility_label`].
+    pub fn with_accessibility_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.accessibility_label = Some(label.into());
+        self
+    }
 
-impl RenderOnce for ComponentExample {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let base = div().flex();
+    /// Restricts the component to the given platforms. See
+    /// [`Component::platforms`].
+    pub fn with_platforms(mut self, platforms: impl IntoIterator<Item = Platform>) -> Self {
+        self.platforms = platforms.into_iter().collect();
+        self
+    }
 
-        let base = match self.label_side {
-            ExampleLabelSide::Right => base.flex_row(),
-            ExampleLabelSide::Left => base.flex_row_reverse(),
-            ExampleLabelSide::Bottom => base.flex_col(),
-            ExampleLabelSide::Top => base.flex_col_reverse(),
-        };
+    /// Sets the version of the component. See [`Component::version`].
+    pub fn with_version(mut self, version: impl Into<SharedString>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
 
-        base.gap_2()
-            .p_2()
-            .text_size(px(10.))
-            .text_color(cx.theme().colors().text_muted)
-            .when(self.grow, |this| this.flex_1())
-            .when(!self.grow, |this| this.flex_none())
-            .child(self.element)
-            .child(self.variant_name)
-            .into_any_element()
+    /// Sets the version the component was introduced in. See
+    /// [`Component::since`].
+    pub fn with_since(mut self, since: impl Into<SharedString>) -> Self {
+        self.since = Some(since.into());
+        self
     }
-}
 
-impl ComponentExample {
-    /// Create a new example with the given variant name and example value.
-    pub fn new(variant_name: impl Into<SharedString>, element: AnyElement) -> Self {
-        Self {
-            variant_name: variant_name.into(),
-            element,
-            label_side: ExampleLabelSide::default(),
-            grow: false,
+    /// Sets the status of the component. [`ComponentStatus::Deprecated`]
+    /// marks it as deprecated, and the status of a deprecated component
+    /// stays [`ComponentStatus::Deprecated`].
+    pub fn with_status(mut self, status: ComponentStatus) -> Self {
+        if status == ComponentStatus::Deprecated {
+            self.deprecated = true;
+        }
+        if !self.deprecated {
+            self.status = status;
         }
+        self
     }
 
-    /// Set the example to grow to fill the available horizontal space.
-    pub fn grow(mut self) -> Self {
-        self.grow = true;
+    /// Marks the component as deprecated, with a message saying why or what
+    /// to use instead. See [`Component::deprecation_message`].
+    pub fn with_deprecation_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.deprecated = true;
+        self.deprecation_message = Some(message.into());
+        self.status = ComponentStatus::Deprecated;
+        self
+    }
+
+    /// Sets the names of related components. See [`Component::related`].
+    pub fn with_related(mut self, related: impl IntoIterator<Item = &'static str>) -> Self {
+        self.related = related.into_iter().collect();
+        self
+    }
+
+    /// Sets where the component is defined. See
+    /// [`Component::source_location`].
+    pub fn with_source_location(mut self, location: &'static Location<'static>) -> Self {
+        self.source_location = Some(location);
+        self
+    }
+
+    /// Sets the tags of the component, dropping case-insensitive duplicates.
+    /// See [`Component::tags`].
+    pub fn with_tags(mut self, tags: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.tags = dedup_case_insensitive(tags);
+        self
+    }
+
+    /// Sets the keywords the component can be searched by, dropping
+    /// case-insensitive duplicates. See [`Component::keywords`].
+    pub fn with_keywords(
+        mut self,
+        keywords: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.keywords = dedup_case_insensitive(keywords);
+        self
+    }
+
+    /// Sets the other names the component can be searched by, dropping
+    /// case-insensitive duplicates. See [`Component::aliases`].
+    pub fn with_aliases(
+        mut self,
+        aliases: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.aliases = dedup_case_insensitive(aliases);
+        self
+    }
+
+    /// Adds a preview of the component under the given label, replacing any
+    /// preview previously added under that label.
+    pub fn with_preview(mut self, label: &'static str, preview: ComponentPreviewFn) -> Self {
+        insert_preview(&mut self.previews, label, preview);
+        self
+    }
+
+    pub fn id(&self) -> ComponentId {
+        self.id.clone()
+    }
+
+    pub fn id_str(&self) -> &str {
+        self.id.as_str()
+    }
+
+    pub fn name(&self) -> SharedString {
+        self.name.clone()
+    }
+
+    /// Returns the name without its module path. See
+    /// [`ComponentId::short_name`].
+    pub fn short_name(&self) -> &str {
+        short_type_name(&self.name)
+    }
+
+    /// The key components are sorted by "name" with: their short name
+    /// ignoring case, then their full name to order components of the same
+    /// name from different modules.
+    fn sort_key(&self) -> (String, SharedString) {
+        (self.short_name().to_lowercase(), self.name())
+    }
+
+    pub fn scope(&self) -> Option<ComponentScope> {
+        self.scope.clone()
+    }
+
+    pub fn description(&self) -> Option<SharedString> {
+        self.description.clone()
+    }
+
+    pub fn version(&self) -> Option<SharedString> {
+        self.version.clone()
+    }
+
+    pub fn since(&self) -> Option<SharedString> {
+        self.since.clone()
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    pub fn deprecation_message(&self) -> Option<SharedString> {
+        self.deprecation_message.clone()
+    }
+
+    pub fn status(&self) -> ComponentStatus {
+        self.status
+    }
+
+    pub fn tags(&self) -> &[SharedString] {
+        &self.tags
+    }
+
+    pub fn keywords(&self) -> Vec<SharedString> {
+        self.keywords.clone()
+    }
+
+    pub fn aliases(&self) -> &[SharedString] {
+        &self.aliases
+    }
+
+    /// Returns where the component is defined, if known
+    pub fn source_location(&self) -> Option<&'static Location<'static>> {
+        self.source_location
+    }
+
+    /// Returns the platforms the component is restricted to. Empty means
+    /// every platform.
+    pub fn platforms(&self) -> &[Platform] {
+        &self.platforms
+    }
+
+    /// Returns the label read out by screen readers, if the component has one
+    pub fn accessibility_label(&self) -> Option<SharedString> {
+        self.accessibility_label.clone()
+    }
+
+    /// Returns whether the component renders correctly on the given platform
+    pub fn supports_platform(&self, platform: Platform) -> bool {
+        self.platforms.is_empty() || self.platforms.contains(&platform)
+    }
+
+    /// Returns the related components that are registered
+    pub fn related(&self) -> Vec<ComponentId> {
+        let data = COMPONENT_DATA.read();
+        self.related
+            .iter()
+            .copied()
+            .filter(|name| data.components.contains_key(*name))
+            .map(ComponentId::new_static)
+            .collect()
+    }
+
+    /// Returns whether the component has any previews
+    pub fn has_preview(&self) -> bool {
+        !self.previews.is_empty()
+    }
+
+    /// Returns the first of the component's previews
+    pub fn preview(&self) -> Option<fn(&mut Window, &mut App) -> AnyElement> {
+        self.previews.first().map(|(_, preview)| *preview)
+    }
+
+    /// Renders a compact card with the component's name, scope and
+    /// description, for components without a preview
+    pub fn render_card(&self, cx: &App) -> AnyElement {
+        let colors = cx.theme().colors();
+        div()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .max_w_80()
+            .p_2()
+            .rounded_md()
+            .border_1()
+            .border_color(colors.border)
+            .bg(colors.surface_background)
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(colors.text)
+                    .child(self.short_name().to_string()),
+            )
+            .when_some(self.scope.as_ref(), |this, scope| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(colors.text_muted)
+                        .child(scope.to_string()),
+                )
+            })
+            .when_some(self.description.clone(), |this, description| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(colors.text_muted)
+                        .child(description),
+                )
+            })
+            .into_any_element()
+    }
+
+    /// Renders the component's first preview, or an error in its place if the
+    /// preview panics or the component doesn't have one.
+    ///
+    /// Only panics in the preview function itself are caught. Panics while the
+    /// element it returns is rendered, laid out or painted happen later, in
+    /// the middle of drawing the window, and aren't caught. Panics are also
+    /// only caught if the process's panic hook lets them unwind; see
+    /// [`is_catching_preview_panics`].
+    pub fn render_preview_safe(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        match self.preview() {
+            Some(preview) => self.render_safe(preview, window, cx),
+            None => self.render_preview_error("no preview registered", cx),
+        }
+    }
+
+    /// Renders the component's first preview in both the light and the dark
+    /// theme the user has configured side by side, without changing the
+    /// active theme
+    pub fn render_preview_themed(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let Some(preview) = self.preview() else {
+            return self.render_preview_error("no preview registered", cx);
+        };
+
+        let (light, dark) = match &ThemeSettings::get_global(cx).theme_selection {
+            Some(ThemeSelection::Dynamic { light, dark, .. }) => (light.clone(), dark.clone()),
+            _ => (
+                ThemeSettings::default_theme(Appearance::Light).to_string(),
+                ThemeSettings::default_theme(Appearance::Dark).to_string(),
+            ),
+        };
+        let registry = ThemeRegistry::global(cx);
+
+        let mut themed_previews = div().flex().gap_4();
+        for (label, theme_name) in [("Light", light), ("Dark", dark)] {
+            let theme = match registry.get(&theme_name) {
+                Ok(theme) => theme,
+                Err(error) => {
+                    themed_previews =
+                        themed_previews.child(self.render_preview_error(&error.to_string(), cx));
+                    continue;
+                }
+            };
+            let element = theme::with_theme_override(theme.clone(), cx, |cx| {
+                self.render_safe(preview, window, cx)
+            });
+            themed_previews = themed_previews.child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_2()
+                    .p_4()
+                    .bg(theme.colors().background)
+                    .text_color(theme.colors().text_muted)
+                    .child(div().text_size(px(10.)).child(label))
+                    .child(Themed {
+                        element,
+                        theme: theme.clone(),
+                    }),
+            );
+        }
+        themed_previews.into_any_element()
+    }
+
+    /// Renders the given preview, or an error in its place if it panics
+    fn render_safe(
+        &self,
+        preview: ComponentPreviewFn,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> AnyElement {
+        match render_preview_catching_panics(preview, window, cx) {
+            Ok(element) => element,
+            Err(message) => {
+                log::error!("preview of component {:?} panicked: {message}", self.name);
+                self.render_preview_error(&message, cx)
+            }
+        }
+    }
+
+    fn render_preview_error(&self, message: &str, cx: &App) -> AnyElement {
+        div()
+            .debug_selector(|| "component-preview-error".into())
+            .flex()
+            .flex_col()
+            .gap_1()
+            .p_2()
+            .border_1()
+            .border_color(cx.theme().status().error)
+            .text_color(cx.theme().status().error)
+            .child(format!("Failed to render {}", self.name))
+            .child(message.to_string())
+            .into_any_element()
+    }
+
+    /// Returns all of the component's previews along with their labels
+    pub fn previews(&self) -> &[(&'static str, ComponentPreviewFn)] {
+        &self.previews
+    }
+
+    /// Returns the component's [`ComponentPreview::preview_variants`], if it
+    /// has a preview
+    pub fn preview_variants(&self) -> Option<ComponentPreviewVariantsFn> {
+        self.preview_variants
+    }
+
+    /// Returns the width and height the component's preview should be shown
+    /// at, if it has one
+    pub fn preview_size(&self) -> Option<(f32, f32)> {
+        self.preview_size
+    }
+
+    /// Returns whether the component's short name, aliases, description, tags
+    /// or keywords contain the query, ignoring case. This is the check
+    /// [`AllComponents::search`] filters by.
+    pub fn matches(&self, query: &str) -> bool {
+        self.match_kind(&query.trim().to_lowercase()).is_some()
+    }
+
+    /// Returns which field matches the query, if any. The query is expected to
+    /// be lowercase.
+    fn match_kind(&self, query: &str) -> Option<ComponentMatchKind> {
+        if self.short_name().to_lowercase().contains(query) {
+            Some(ComponentMatchKind::Name)
+        } else if self
+            .aliases
+            .iter()
+            .any(|alias| alias.to_lowercase().contains(query))
+        {
+            Some(ComponentMatchKind::Alias)
+        } else if self
+            .description
+            .as_ref()
+            .is_some_and(|description| description.to_lowercase().contains(query))
+        {
+            Some(ComponentMatchKind::Description)
+        } else if self
+            .tags
+            .iter()
+            .any(|tag| tag.to_lowercase().contains(query))
+        {
+            Some(ComponentMatchKind::Tag)
+        } else if self
+            .keywords
+            .iter()
+            .any(|keyword| keyword.to_lowercase().contains(query))
+        {
+            Some(ComponentMatchKind::Keyword)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the fuzzy match score of the query against this component, if
+    /// it matches. The query is expected to be lowercase.
+    fn fuzzy_score(&self, query: &str) -> Option<f32> {
+        if let Some(score) = fuzzy_score(&self.short_name().to_lowercase(), query) {
+            return Some(0.5 + 0.5 * score);
+        }
+        self.description
+            .iter()
+            .chain(&self.keywords)
+            .filter_map(|text| fuzzy_score(&text.to_lowercase(), query))
+            .max_by(f32::total_cmp)
+            .map(|score| 0.5 * score)
+    }
+}
+
+thread_local! {
+    /// Whether a preview is being rendered by
+    /// [`render_preview_catching_panics`] on this thread.
+    static CATCHING_PREVIEW_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Returns whether a panic on this thread would be caught by a component
+/// preview and rendered as an error. This is only the case while a preview
+/// function runs, not while the element it returns is drawn.
+///
+/// Panic hooks that exit or abort the process must return early when this is
+/// `true`, or panicking previews will still take the process down before the
+/// panic can be caught.
+pub fn is_catching_preview_panics() -> bool {
+    CATCHING_PREVIEW_PANICS.get()
+}
+
+/// Calls the given preview, returning the panic message if it panics. The
+/// element it returns is drawn later, and panics while drawing it aren't
+/// caught.
+fn render_preview_catching_panics(
+    preview: ComponentPreviewFn,
+    window: &mut Window,
+    cx: &mut App,
+) -> Result<AnyElement, String> {
+    let was_catching = CATCHING_PREVIEW_PANICS.replace(true);
+    let result = catch_unwind(AssertUnwindSafe(|| preview(window, cx)));
+    CATCHING_PREVIEW_PANICS.set(was_catching);
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+/// Renders every registered preview once, returning the components whose
+/// previews panicked or rendered an empty element, along with what went
+/// wrong. A preview counts as empty when it's laid out with no width or no
+/// height at its min-content size.
+#[cfg(any(test, feature = "test-support"))]
+pub fn test_all_previews(cx: &mut gpui::VisualTestContext) -> Vec<(ComponentId, String)> {
+    let components = components().all_previews_sorted();
+    let mut failures = Vec::new();
+    for component in &components {
+        for (label, preview) in component.previews() {
+            let mut panic_message = None;
+            let (_, bounds) = cx.draw(
+                gpui::point(px(0.), px(0.)),
+                gpui::size(
+                    gpui::AvailableSpace::MinContent,
+                    gpui::AvailableSpace::MinContent,
+                ),
+                |window, cx| {
+                    let element = match render_preview_catching_panics(*preview, window, cx) {
+                        Ok(element) => element,
+                        Err(message) => {
+                            panic_message = Some(message);
+                            div().into_any_element()
+                        }
+                    };
+                    MeasuredPreview { element }
+                },
+            );
+            let message = match panic_message {
+                Some(message) => message,
+                None if bounds.size.width <= px(0.) || bounds.size.height <= px(0.) => {
+                    "rendered an empty element".to_string()
+                }
+                None => continue,
+            };
+            failures.push((component.id(), format!("{label}: {message}")));
+        }
+    }
+    failures
+}
+
+/// An element that lays out its child as is and reports the child's bounds
+/// from prepaint, for [`test_all_previews`].
+#[cfg(any(test, feature = "test-support"))]
+struct MeasuredPreview {
+    element: AnyElement,
+}
+
+#[cfg(any(test, feature = "test-support"))]
+impl Element for MeasuredPreview {
+    type RequestLayoutState = ();
+    type PrepaintState = Bounds<Pixels>;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        (self.element.request_layout(window, cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        self.element.prepaint(window, cx);
+        bounds
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.element.paint(window, cx);
+    }
+}
+
+#[cfg(any(test, feature = "test-support"))]
+impl IntoElement for MeasuredPreview {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+/// Scores how well `query` matches `candidate`, both expected to be
+/// lowercase. Contiguous matches score in `0.5..=1.0` and scattered
+/// subsequence matches score below `0.5`. Within each kind, matches covering
+/// more of the candidate and starting earlier in it score higher.
+fn fuzzy_score(candidate: &str, query: &str) -> Option<f32> {
+    if query.is_empty() {
+        return Some(1.0);
+    }
+
+    let query_len = query.chars().count() as f32;
+    let candidate_len = candidate.chars().count() as f32;
+
+    if let Some(byte_ix) = candidate.find(query) {
+        let start = candidate[..byte_ix].chars().count() as f32;
+        let coverage = query_len / candidate_len;
+        let earliness = 1.0 - start / candidate_len;
+        return Some(0.5 + 0.25 * coverage + 0.25 * earliness);
+    }
+
+    let mut query_chars = query.chars().peekable();
+    let mut start = None;
+    let mut end = 0;
+    for (ix, c) in candidate.chars().enumerate() {
+        if query_chars.peek() == Some(&c) {
+            start.get_or_insert(ix);
+            end = ix;
+            query_chars.next();
+        }
+    }
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    let start = start.unwrap_or(0);
+    let density = query_len / (end - start + 1) as f32;
+    let earliness = 1.0 - start as f32 / candidate_len;
+    Some(0.25 * density + 0.25 * earliness)
+}
+
+/// How mature a component is
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum ComponentStatus {
+    /// Still being designed and likely to change
+    Experimental,
+    /// Mostly settled, but may still change
+    Beta,
+    /// Ready for general use
+    #[default]
+    Stable,
+    /// Shouldn't be used in new code
+    Deprecated,
+}
+
+/// A platform a component can be restricted to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum Platform {
+    MacOs,
+    Linux,
+    Windows,
+}
+
+impl Platform {
+    /// Returns the platform being compiled for, if it's one of the above
+    pub fn current() -> Option<Platform> {
+        if cfg!(target_os = "macos") {
+            Some(Platform::MacOs)
+        } else if cfg!(any(target_os = "linux", target_os = "freebsd")) {
+            Some(Platform::Linux)
+        } else if cfg!(target_os = "windows") {
+            Some(Platform::Windows)
+        } else {
+            None
+        }
+    }
+}
+
+/// Which field of a component matched a search query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentMatchKind {
+    /// The query matched the component's name
+    Name,
+    /// The query matched one of the component's aliases
+    Alias,
+    /// The query matched the component's description
+    Description,
+    /// The query matched one of the component's tags
+    Tag,
+    /// The query matched one of the component's keywords
+    Keyword,
+}
+
+/// A component returned from [`AllComponents::search_matches`]
+#[derive(Clone)]
+pub struct ComponentSearchMatch {
+    pub component: ComponentMetadata,
+    pub kind: ComponentMatchKind,
+}
+
+#[derive(Clone)]
+pub struct AllComponents(pub HashMap<ComponentId, ComponentMetadata>);
+
+impl AllComponents {
+    pub fn new() -> Self {
+        AllComponents(HashMap::default())
+    }
+
+    /// Removes all components for which the predicate returns `false`
+    pub fn retain(&mut self, f: impl Fn(&ComponentMetadata) -> bool) {
+        self.0.retain(|_, component| f(component));
+    }
+
+    /// Returns whether a component with the given id is included
+    pub fn has_component(&self, id: &ComponentId) -> bool {
+        self.0.contains_key(id)
+    }
+
+    /// Returns the component with the given id
+    pub fn get_component(&self, id: &ComponentId) -> Option<&ComponentMetadata> {
+        self.0.get(id)
+    }
+
+    /// Returns a component picked at random, or `None` if there are no
+    /// components. This isn't meant to be statistically sound, just to vary
+    /// between calls.
+    pub fn random_component(&self) -> Option<&ComponentMetadata> {
+        if self.0.is_empty() {
+            return None;
+        }
+        // Xorshift, seeded from the clock. The seed must be nonzero.
+        let mut state = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+            | 1;
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        self.0.values().nth((state % self.0.len() as u64) as usize)
+    }
+
+    /// Returns all components with previews
+    pub fn all_previews(&self) -> Vec<&ComponentMetadata> {
+        self.0.values().filter(|c| c.has_preview()).collect()
+    }
+
+    /// Returns all components with previews sorted by name
+    pub fn all_previews_sorted(&self) -> Vec<ComponentMetadata> {
+        let mut previews: Vec<ComponentMetadata> =
+            self.all_previews().into_iter().cloned().collect();
+        previews.sort_by_cached_key(|a| a.sort_key());
+        previews
+    }
+
+    /// Returns all components with previews that aren't deprecated sorted by
+    /// name
+    pub fn all_previews_sorted_excluding_deprecated(&self) -> Vec<ComponentMetadata> {
+        let mut previews = self.all_previews_sorted();
+        previews.retain(|c| !c.is_deprecated());
+        previews
+    }
+
+    /// Returns all components with the given status
+    pub fn by_status(&self, status: ComponentStatus) -> Vec<&ComponentMetadata> {
+        self.0.values().filter(|c| c.status == status).collect()
+    }
+
+    /// Returns all deprecated components
+    pub fn deprecated_components(&self) -> Vec<&ComponentMetadata> {
+        self.0.values().filter(|c| c.is_deprecated()).collect()
+    }
+
+    /// Returns all components
+    pub fn all(&self) -> Vec<&ComponentMetadata> {
+        self.0.values().collect()
+    }
+
+    /// Returns all components sorted by name
+    pub fn all_sorted(&self) -> Vec<ComponentMetadata> {
+        let mut components: Vec<ComponentMetadata> = self.all().into_iter().cloned().collect();
+        components.sort_by_cached_key(|a| a.sort_key());
+        components
+    }
+
+    /// Returns all components in the given scope
+    pub fn filter_by_scope(&self, scope: &ComponentScope) -> Vec<&ComponentMetadata> {
+        self.0
+            .values()
+            .filter(|c| c.scope.as_ref() == Some(scope))
+            .collect()
+    }
+
+    /// Returns all components in the given scope sorted by name
+    pub fn filter_by_scope_sorted(&self, scope: &ComponentScope) -> Vec<ComponentMetadata> {
+        let mut components: Vec<ComponentMetadata> =
+            self.filter_by_scope(scope).into_iter().cloned().collect();
+        components.sort_by_cached_key(|a| a.sort_key());
+        components
+    }
+
+    /// Returns all components in the given scope, same as
+    /// [`Self::filter_by_scope`]
+    pub fn by_scope(&self, scope: &ComponentScope) -> Vec<&ComponentMetadata> {
+        self.filter_by_scope(scope)
+    }
+
+    /// Returns all components in the given scope sorted by name, same as
+    /// [`Self::filter_by_scope_sorted`]
+    pub fn by_scope_sorted(&self, scope: &ComponentScope) -> Vec<ComponentMetadata> {
+        self.filter_by_scope_sorted(scope)
+    }
+
+    /// Returns all components with the given tag, ignoring case
+    pub fn filter_by_tag(&self, tag: &str) -> Vec<&ComponentMetadata> {
+        let tag = tag.to_lowercase();
+        self.0
+            .values()
+            .filter(|c| c.tags.iter().any(|t| t.to_lowercase() == tag))
+            .collect()
+    }
+
+    /// Returns all components that render correctly on the given platform,
+    /// including those that aren't restricted to any platform
+    pub fn filter_by_platform(&self, platform: Platform) -> Vec<&ComponentMetadata> {
+        self.0
+            .values()
+            .filter(|c| c.supports_platform(platform))
+            .collect()
+    }
+
+    /// Returns all components without an accessibility label, sorted by name
+    pub fn missing_accessibility_labels(&self) -> Vec<&ComponentMetadata> {
+        let mut components: Vec<&ComponentMetadata> = self
+            .0
+            .values()
+            .filter(|c| c.accessibility_label.is_none())
+            .collect();
+        components.sort_by_cached_key(|a| a.sort_key());
+        components
+    }
+
+    /// Returns all components that don't belong to a scope
+    pub fn without_scope(&self) -> Vec<&ComponentMetadata> {
+        self.0.values().filter(|c| c.scope.is_none()).collect()
+    }
+
+    /// Returns all components whose short name, aliases, description, tags
+    /// or keywords contain the query, ignoring case, sorted by name. An empty query returns every
+    /// component.
+    ///
+    /// Use [`Self::fuzzy_search`] for subsequence matching ranked by relevance.
+    pub fn search(&self, query: &str) -> Vec<ComponentMetadata> {
+        self.search_matches(query)
+            .into_iter()
+            .map(|search_match| search_match.component)
+            .collect()
+    }
+
+    /// Like [`Self::search`], but also reports which field matched the query
+    pub fn search_matches(&self, query: &str) -> Vec<ComponentSearchMatch> {
+        let query = query.trim().to_lowercase();
+        let mut matches: Vec<ComponentSearchMatch> = self
+            .0
+            .values()
+            .filter_map(|component| {
+                let kind = component.match_kind(&query)?;
+                Some(ComponentSearchMatch {
+                    component: component.clone(),
+                    kind,
+                })
+            })
+            .collect();
+        matches.sort_by_cached_key(|search_match| search_match.component.sort_key());
+        matches
+    }
+
+    /// Returns the number of components in each scope, same as
+    /// [`Self::scope_counts`]
+    pub fn count_by_scope(&self) -> HashMap<Option<ComponentScope>, usize> {
+        self.scope_counts()
+    }
+
+    /// Returns the number of components
+    pub fn total_count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the number of components with previews
+    pub fn preview_count(&self) -> usize {
+        self.0.values().filter(|c| c.has_preview()).count()
+    }
+
+    /// Returns components missing a preview or a description, sorted by name
+    pub fn undocumented(&self) -> Vec<ComponentMetadata> {
+        let mut components: Vec<ComponentMetadata> = self
+            .0
+            .values()
+            .filter(|c| !c.has_preview() || c.description.is_none())
+            .cloned()
+            .collect();
+        components.sort_by_cached_key(|a| a.sort_key());
+        components
+    }
+
+    /// Returns the fraction of components with both a preview and a
+    /// description, in `0.0..=1.0`. With no components, this is `1.0`.
+    pub fn documentation_coverage(&self) -> f32 {
+        if self.0.is_empty() {
+            return 1.0;
+        }
+        let undocumented = self
+            .0
+            .values()
+            .filter(|c| !c.has_preview() || c.description.is_none())
+            .count();
+        1.0 - undocumented as f32 / self.0.len() as f32
+    }
+
+    /// Returns all components fuzzy-matching the query along with a relevance
+    /// score in `0.0..=1.0`, sorted by descending score and then by name.
+    ///
+    /// Name matches always outrank description and keyword matches, and
+    /// contiguous matches outrank scattered ones.
+    pub fn fuzzy_search(&self, query: &str) -> Vec<(ComponentMetadata, f32)> {
+        let query = query.trim().to_lowercase();
+        let mut matches: Vec<(ComponentMetadata, f32)> = self
+            .0
+            .values()
+            .filter_map(|component| {
+                let score = component.fuzzy_score(&query)?;
+                Some((component.clone(), score))
+            })
+            .collect();
+        matches.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .total_cmp(a_score)
+                .then_with(|| a.sort_key().cmp(&b.sort_key()))
+        });
+        matches
+    }
+
+    /// Returns all components bucketed by scope, each bucket sorted by name.
+    /// Components without a scope are bucketed under `None`.
+    pub fn grouped_by_scope(&self) -> HashMap<Option<ComponentScope>, Vec<ComponentMetadata>> {
+        let mut groups: HashMap<Option<ComponentScope>, Vec<ComponentMetadata>> =
+            HashMap::default();
+        for component in self.0.values() {
+            groups
+                .entry(component.scope())
+                .or_default()
+                .push(component.clone());
+        }
+        for components in groups.values_mut() {
+            components.sort_by_cached_key(|a| a.sort_key());
+        }
+        groups
+    }
+
+    /// Returns the number of components in each scope
+    pub fn scope_counts(&self) -> HashMap<Option<ComponentScope>, usize> {
+        let mut counts: HashMap<Option<ComponentScope>, usize> = HashMap::default();
+        for component in self.0.values() {
+            *counts.entry(component.scope()).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Returns all components grouped by scope, each group sorted by name.
+    /// Groups are ordered by scope, with unscoped components last.
+    pub fn group_by_scope(&self) -> BTreeMap<ScopeKey, Vec<ComponentMetadata>> {
+        let mut groups: BTreeMap<ScopeKey, Vec<ComponentMetadata>> = BTreeMap::new();
+        for component in self.0.values() {
+            groups
+                .entry(ScopeKey(component.scope()))
+                .or_default()
+                .push(component.clone());
+        }
+        for components in groups.values_mut() {
+            components.sort_by_cached_key(|a| a.sort_key());
+        }
+        groups
+    }
+
+    /// Groups components by the uppercased first letter of their short name,
+    /// sorted by name within each group. Names that don't start with a letter
+    /// are grouped under `'#'`.
+    pub fn grouped_by_initial(&self) -> BTreeMap<char, Vec<ComponentMetadata>> {
+        let mut groups: BTreeMap<char, Vec<ComponentMetadata>> = BTreeMap::new();
+        for component in self.0.values() {
+            let initial = component
+                .short_name()
+                .chars()
+                .next()
+                .filter(|initial| initial.is_alphabetic())
+                .and_then(|initial| initial.to_uppercase().next())
+                .unwrap_or('#');
+            groups.entry(initial).or_default().push(component.clone());
+        }
+        for components in groups.values_mut() {
+            components.sort_by_cached_key(|a| a.sort_key());
+        }
+        groups
+    }
+
+    /// Returns the scopes components belong to, each once. Built-in scopes
+    /// come first and unknown scopes after, each sorted by name, with `None`
+    /// last if any component doesn't belong to a scope.
+    pub fn sorted_scopes(&self) -> Vec<Option<ComponentScope>> {
+        let mut scopes: Vec<Option<ComponentScope>> = self
+            .0
+            .values()
+            .map(|component| &component.scope)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .cloned()
+            .collect();
+        scopes.sort_by_cached_key(|scope| match scope {
+            Some(ComponentScope::Unknown(name)) => (1, name.to_string()),
+            Some(scope) => (0, scope.to_string()),
+            None => (2, String::new()),
+        });
+        scopes
+    }
+}
+
+impl Deref for AllComponents {
+    type Target = HashMap<ComponentId, ComponentMetadata>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AllComponents {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Returns all registered components. The result is cached and shared
+/// between callers until new components or previews have been registered.
+///
+/// [`init`] must have run first; see the crate documentation.
+pub fn components() -> Arc<AllComponents> {
+    let data = COMPONENT_DATA.read();
+    debug_assert!(
+        data.initialized,
+        "component::components() was called before component::init(), so no components are registered yet"
+    );
+    let mut cached_components = data.cached_components.lock();
+
+    if let Some((generation, all_components)) = cached_components.as_ref() {
+        if *generation == data.generation {
+            return all_components.clone();
+        }
+    }
+
+    let all_components = Arc::new(data.build_components());
+    *cached_components = Some((data.generation, all_components.clone()));
+    all_components
+}
+
+/// Returns the metadata of a single registered component without building the
+/// full set of [`components`].
+pub fn component_by_id(id: &ComponentId) -> Option<ComponentMetadata> {
+    COMPONENT_DATA.read().get_by_name(id.as_str())
+}
+
+/// Scopes are ordered as declared, with [`ComponentScope::Unknown`] scopes
+/// last, sorted by name.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ComponentScope {
+    Layout,
+    Input,
+    Notification,
+    Editor,
+    Collaboration,
+    VersionControl,
+    Navigation,
+    Settings,
+    Form,
+    Overlay,
+    Feedback,
+    Typography,
+    Unknown(SharedString),
+}
+
+impl ComponentScope {
+    /// Returns every scope except [`ComponentScope::Unknown`], in order.
+    pub fn built_in() -> &'static [ComponentScope] {
+        static BUILT_IN: [ComponentScope; 12] = [
+            ComponentScope::Layout,
+            ComponentScope::Input,
+            ComponentScope::Notification,
+            ComponentScope::Editor,
+            ComponentScope::Collaboration,
+            ComponentScope::VersionControl,
+            ComponentScope::Navigation,
+            ComponentScope::Settings,
+            ComponentScope::Form,
+            ComponentScope::Overlay,
+            ComponentScope::Feedback,
+            ComponentScope::Typography,
+        ];
+        &BUILT_IN
+    }
+
+    /// Infers a built-in scope from the module a type is defined in, like
+    /// [`ComponentScope::Layout`] for `ui::layout::Panel`. Modules are matched
+    /// ignoring case and underscores, so `version_control` matches
+    /// [`ComponentScope::VersionControl`].
+    pub fn from_type_path(path: &str) -> Option<ComponentScope> {
+        let path = path.split('<').next().unwrap_or(path);
+        let mut segments = path.rsplit("::");
+        segments.next()?;
+        Self::from_module_name(segments.next()?)
+    }
+
+    /// Infers a scope from a module path like `ui::components::layout`,
+    /// using the innermost module that names a built-in scope, matched like
+    /// [`Self::from_type_path`]. If none does, the scope is
+    /// [`ComponentScope::Unknown`], named after the innermost module.
+    pub fn from_module_path(module_path: &str) -> Self {
+        module_path
+            .rsplit("::")
+            .find_map(Self::from_module_name)
+            .unwrap_or_else(|| {
+                let last_segment = module_path.rsplit("::").next().unwrap_or(module_path);
+                ComponentScope::Unknown(SharedString::new(last_segment))
+            })
+    }
+
+    fn from_module_name(module: &str) -> Option<ComponentScope> {
+        let module = module.replace('_', "");
+        Self::built_in()
+            .iter()
+            .find(|scope| {
+                scope
+                    .to_string()
+                    .replace(' ', "")
+                    .eq_ignore_ascii_case(&module)
+            })
+            .cloned()
+    }
+}
+
+impl Display for ComponentScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComponentScope::Layout => write!(f, "Layout"),
+            ComponentScope::Input => write!(f, "Input"),
+            ComponentScope::Notification => write!(f, "Notification"),
+            ComponentScope::Editor => write!(f, "Editor"),
+            ComponentScope::Collaboration => write!(f, "Collaboration"),
+            ComponentScope::VersionControl => write!(f, "Version Control"),
+            ComponentScope::Navigation => write!(f, "Navigation"),
+            ComponentScope::Settings => write!(f, "Settings"),
+            ComponentScope::Form => write!(f, "Form"),
+            ComponentScope::Overlay => write!(f, "Overlay"),
+            ComponentScope::Feedback => write!(f, "Feedback"),
+            ComponentScope::Typography => write!(f, "Typography"),
+            ComponentScope::Unknown(name) => write!(f, "Unknown: {}", name),
+        }
+    }
+}
+
+/// An optional scope used to group components, ordered like
+/// [`ComponentScope`] with components without a scope last.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScopeKey(pub Option<ComponentScope>);
+
+impl Ord for ScopeKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+impl PartialOrd for ScopeKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Each built-in scope has a stable numeric discriminant for compact
+/// encodings, which external tools can rely on:
+///
+/// | Scope          | Discriminant |
+/// |----------------|--------------|
+/// | Layout         | 0            |
+/// | Input          | 1            |
+/// | Notification   | 2            |
+/// | Editor         | 3            |
+/// | Collaboration  | 4            |
+/// | VersionControl | 5            |
+/// | Navigation     | 6            |
+/// | Settings       | 7            |
+/// | Form           | 8            |
+/// | Overlay        | 9            |
+/// | Feedback       | 10           |
+/// | Typography     | 11           |
+/// | Unknown        | 255          |
+///
+/// Unknown scopes lose their name when encoded, so 255 can't be decoded.
+impl From<ComponentScope> for u8 {
+    fn from(scope: ComponentScope) -> Self {
+        match scope {
+            ComponentScope::Layout => 0,
+            ComponentScope::Input => 1,
+            ComponentScope::Notification => 2,
+            ComponentScope::Editor => 3,
+            ComponentScope::Collaboration => 4,
+            ComponentScope::VersionControl => 5,
+            ComponentScope::Navigation => 6,
+            ComponentScope::Settings => 7,
+            ComponentScope::Form => 8,
+            ComponentScope::Overlay => 9,
+            ComponentScope::Feedback => 10,
+            ComponentScope::Typography => 11,
+            ComponentScope::Unknown(_) => 255,
+        }
+    }
+}
+
+impl TryFrom<u8> for ComponentScope {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> anyhow::Result<Self> {
+        Ok(match value {
+            0 => ComponentScope::Layout,
+            1 => ComponentScope::Input,
+            2 => ComponentScope::Notification,
+            3 => ComponentScope::Editor,
+            4 => ComponentScope::Collaboration,
+            5 => ComponentScope::VersionControl,
+            6 => ComponentScope::Navigation,
+            7 => ComponentScope::Settings,
+            8 => ComponentScope::Form,
+            9 => ComponentScope::Overlay,
+            10 => ComponentScope::Feedback,
+            11 => ComponentScope::Typography,
+            255 => anyhow::bail!("unknown component scopes can't be decoded without their name"),
+            _ => anyhow::bail!("invalid component scope discriminant {value}"),
+        })
+    }
+}
+
+impl From<&str> for ComponentScope {
+    fn from(value: &str) -> Self {
+        match value {
+            "Layout" => ComponentScope::Layout,
+            "Input" => ComponentScope::Input,
+            "Notification" => ComponentScope::Notification,
+            "Editor" => ComponentScope::Editor,
+            "Collaboration" => ComponentScope::Collaboration,
+            "Version Control" | "VersionControl" => ComponentScope::VersionControl,
+            "Navigation" => ComponentScope::Navigation,
+            "Settings" => ComponentScope::Settings,
+            "Form" => ComponentScope::Form,
+            "Overlay" => ComponentScope::Overlay,
+            "Feedback" => ComponentScope::Feedback,
+            "Typography" => ComponentScope::Typography,
+            _ => ComponentScope::Unknown(SharedString::new(value)),
+        }
+    }
+}
+
+impl From<String> for ComponentScope {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Layout" => ComponentScope::Layout,
+            "Input" => ComponentScope::Input,
+            "Notification" => ComponentScope::Notification,
+            "Editor" => ComponentScope::Editor,
+            "Collaboration" => ComponentScope::Collaboration,
+            "Version Control" | "VersionControl" => ComponentScope::VersionControl,
+            "Navigation" => ComponentScope::Navigation,
+            "Settings" => ComponentScope::Settings,
+            "Form" => ComponentScope::Form,
+            "Overlay" => ComponentScope::Overlay,
+            "Feedback" => ComponentScope::Feedback,
+            "Typography" => ComponentScope::Typography,
+            _ => ComponentScope::Unknown(SharedString::new(value)),
+        }
+    }
+}
+
+/// Which side of the preview to show labels on
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExampleLabelSide {
+    /// Left side
+    Left,
+    /// Right side
+    Right,
+    /// Top side
+    #[default]
+    Top,
+    /// Bottom side
+    Bottom,
+    /// No label, for examples that describe themselves
+    None,
+}
+
+/// A single example of a component.
+///
+/// Examples made with [`Self::from_fn`] build their element each time they're
+/// rendered, so clones of them render independently. An example made with
+/// [`Self::new`] holds a single element, which only the first of it and its
+/// clones to render shows.
+#[derive(IntoElement, Clone)]
+pub struct ComponentExample {
+    variant_name: SharedString,
+    element: ExampleElement,
+    label_side: ExampleLabelSide,
+    grow: bool,
+    background: Option<Hsla>,
+    scale: Option<f32>,
+    code: Option<SharedString>,
+    description: Option<SharedString>,
+    padding: Option<Edges<Pixels>>,
+    tooltip: Option<SharedString>,
+    element_id: Option<ElementId>,
+}
+
+/// The element shown by a [`ComponentExample`].
+#[derive(Clone)]
+enum ExampleElement {
+    /// An element built up front, shared between clones and taken by the
+    /// first of them to render.
+    Built(Rc<RefCell<Option<AnyElement>>>),
+    /// Builds the element each time the example renders.
+    Factory(Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>),
+}
+
+impl ExampleElement {
+    fn build(self, variant_name: &SharedString, window: &mut Window, cx: &mut App) -> AnyElement {
+        match self {
+            ExampleElement::Built(element) => element.take().unwrap_or_else(|| {
+                log::error!(
+                    "example {variant_name:?} was already rendered; use ComponentExample::from_fn to render it more than once"
+                );
+                Empty.into_any_element()
+            }),
+            ExampleElement::Factory(build) => build(window, cx),
+        }
+    }
+}
+
+impl RenderOnce for ComponentExample {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let element = self.element.build(&self.variant_name, window, cx);
+        let id = match (self.element_id, &self.tooltip) {
+            (Some(id), _) => Some(id),
+            (None, Some(_)) => Some(ElementId::Name(self.variant_name.clone())),
+            (None, None) => None,
+        };
+        let base = div().flex();
+
+        let base = match self.label_side {
+            ExampleLabelSide::Right => base.flex_row(),
+            ExampleLabelSide::Left => base.flex_row_reverse(),
+            ExampleLabelSide::Bottom => base.flex_col(),
+            ExampleLabelSide::Top => base.flex_col_reverse(),
+            ExampleLabelSide::None => base.flex_col(),
+        };
+        let show_label = self.label_side != ExampleLabelSide::None;
+
+        base.when(show_label, |this| this.gap_2())
+            .map(|this| match self.padding {
+                Some(padding) => this
+                    .pt(padding.top)
+                    .pr(padding.right)
+                    .pb(padding.bottom)
+                    .pl(padding.left),
+                None => this.p_2(),
+            })
+            .text_size(px(10.))
+            .text_color(cx.theme().colors().text_muted)
+            .when(self.grow, |this| this.flex_1())
+            .when(!self.grow, |this| this.flex_none())
+            .map(|this| {
+                let element = match self.scale {
+                    Some(factor) => Scaled { element, factor }.into_any_element(),
+                    None => element,
+                };
+                let element = match self.background {
+                    Some(background) => div().bg(background).child(element).into_any_element(),
+                    None => element,
+                };
+                match self.code {
+                    Some(code) => this.child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_1()
+                            .child(element)
+                            // TODO: Make the snippet selectable, so it can be
+                            // copied.
+                            .child(
+                                div()
+                                    .font(ThemeSettings::get_global(cx).buffer_font.clone())
+                                    .child(code),
+                            ),
+                    ),
+                    None => this.child(element),
+                }
+            })
+            .when(show_label, |this| match self.description {
+                Some(description) => this.child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .min_w_0()
+                        .child(self.variant_name)
+                        .child(div().w_full().child(description)),
+                ),
+                None => this.child(self.variant_name),
+            })
+            .map(|this| match id {
+                Some(id) => this
+                    .id(id)
+                    .when_some(self.tooltip, |this, tooltip| {
+                        this.tooltip(move |_, cx| {
+                            let text = tooltip.clone();
+                            cx.new(|_| ExampleTooltip { text }).into()
+                        })
+                    })
+                    .into_any_element(),
+                None => this.into_any_element(),
+            })
+    }
+}
+
+impl ComponentExample {
+    /// Create a new example with the given variant name and example value.
+    pub fn new<E: IntoElement>(variant_name: impl Into<SharedString>, element: E) -> Self {
+        Self::with_element(
+            variant_name,
+            ExampleElement::Built(Rc::new(RefCell::new(Some(element.into_any_element())))),
+        )
+    }
+
+    /// Create a new example with the given variant name, whose element is
+    /// built by the given function each time the example renders.
+    pub fn from_fn<E: IntoElement>(
+        variant_name: impl Into<SharedString>,
+        build: impl Fn(&mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        Self::with_element(
+            variant_name,
+            ExampleElement::Factory(Rc::new(move |window, cx| {
+                build(window, cx).into_any_element()
+            })),
+        )
+    }
+
+    fn with_element(variant_name: impl Into<SharedString>, element: ExampleElement) -> Self {
+        Self {
+            variant_name: variant_name.into(),
+            element,
+            label_side: ExampleLabelSide::default(),
+            grow: false,
+            background: None,
+            scale: None,
+            code: None,
+            description: None,
+            padding: None,
+            tooltip: None,
+            element_id: None,
+        }
+    }
+
+    /// Set the example to grow to fill the available horizontal space.
+    pub fn grow(mut self) -> Self {
+        self.grow = true;
+        self
+    }
+
+    /// Set which side of the example the label is shown on.
+    pub fn label_side(mut self, side: ExampleLabelSide) -> Self {
+        self.label_side = side;
+        self
+    }
+
+    /// Set the background the example is rendered on, for components that
+    /// are transparent or tinted.
+    pub fn background(mut self, color: Hsla) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Scale the example up or down, for components that are too small to
+    /// make out at their actual size. The factor is clamped to `0.1..=10.0`,
+    /// and factors that aren't finite and positive, like NaN, are treated
+    /// like zero.
+    ///
+    /// Only sizes specified in rems are scaled.
+    pub fn scale(mut self, factor: f32) -> Self {
+        let factor = if factor.is_finite() && factor > 0. {
+            factor
+        } else {
+            0.
+        };
+        self.scale = Some(factor.clamp(0.1, 10.0));
+        self
+    }
+
+    /// Show the code that produced the example below it.
+    pub fn code(mut self, snippet: impl Into<SharedString>) -> Self {
+        self.code = Some(snippet.into());
+        self
+    }
+
+    /// Explain when to use the example's variant below its name.
+    pub fn description(mut self, text: impl Into<SharedString>) -> Self {
+        self.description = Some(text.into());
+        self
+    }
+
+    /// Set the padding around the example, replacing the default padding.
+    pub fn padding(mut self, padding: Edges<Pixels>) -> Self {
+        self.padding = Some(padding);
+        self
+    }
+
+    /// Show the given text in a tooltip when the example is hovered. Unless
+    /// the example has an [`element_id`](Self::element_id), its variant name
+    /// is used as the id the tooltip needs.
+    pub fn tooltip(mut self, text: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
+    /// Give the example's outermost element an id, so tests can find it.
+    pub fn element_id(mut self, id: impl Into<ElementId>) -> Self {
+        self.element_id = Some(id.into());
+        self
+    }
+}
+
+/// The tooltip shown when hovering a [`ComponentExample`].
+struct ExampleTooltip {
+    text: SharedString,
+}
+
+impl Render for ExampleTooltip {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let colors = cx.theme().colors();
+        div()
+            .max_w_80()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .border_1()
+            .border_color(colors.border)
+            .bg(colors.elevated_surface_background)
+            .text_sm()
+            .text_color(colors.text)
+            .child(self.text.clone())
+    }
+}
+
+/// An element that scales the rem size of its child by a factor.
+struct Scaled {
+    element: AnyElement,
+    factor: f32,
+}
+
+impl Element for Scaled {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let rem_size = window.rem_size() * self.factor;
+        let layout_id = window.with_rem_size(Some(rem_size), |window| {
+            self.element.request_layout(window, cx)
+        });
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let rem_size = window.rem_size() * self.factor;
+        window.with_rem_size(Some(rem_size), |window| {
+            self.element.prepaint(window, cx);
+        })
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let rem_size = window.rem_size() * self.factor;
+        window.with_rem_size(Some(rem_size), |window| {
+            self.element.paint(window, cx);
+        })
+    }
+}
+
+impl IntoElement for Scaled {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+/// An element that lays out and paints its child in the given theme instead
+/// of the active theme.
+struct Themed {
+    element: AnyElement,
+    theme: Arc<Theme>,
+}
+
+impl Element for Themed {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let layout_id = theme::with_theme_override(self.theme.clone(), cx, |cx| {
+            self.element.request_layout(window, cx)
+        });
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        theme::with_theme_override(self.theme.clone(), cx, |cx| {
+            self.element.prepaint(window, cx);
+        })
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        theme::with_theme_override(self.theme.clone(), cx, |cx| {
+            self.element.paint(window, cx);
+        })
+    }
+}
+
+impl IntoElement for Themed {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
         self
     }
 }
 
 /// A group of component examples.
-#[derive(IntoElement)]
+///
+/// Clones share the examples made with [`ComponentExample::new`], so groups
+/// meant to be rendered more than once should be made of examples from
+/// [`ComponentExample::from_fn`].
+#[derive(IntoElement, Clone, Default)]
 pub struct ComponentExampleGroup {
     pub title: Option<SharedString>,
     pub examples: Vec<ComponentExample>,
     pub grow: bool,
     pub vertical: bool,
+    pub columns: Option<usize>,
+    pub collapsible: bool,
+    pub max_width: Option<Pixels>,
+    pub min_height: Option<Pixels>,
+    pub max_height: Option<Pixels>,
+    pub gap: Option<Pixels>,
+    pub border: bool,
+    pub scrollable: bool,
+    /// Identifies the group among the elements around it, so it can keep
+    /// state across renders.
+    pub id: Option<ElementId>,
+    /// Groups nested below this group's examples.
+    pub children: Vec<ComponentExampleGroup>,
 }
 
+/// Whether a collapsible example group is collapsed, stored as the element
+/// state of the group.
+#[derive(Clone, Default)]
+struct CollapsedState(Rc<Cell<bool>>);
+
 impl RenderOnce for ComponentExampleGroup {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        match self.element_id() {
+            Some(id) => ExampleGroupElement {
+                id,
+                group: Some(self),
+                element: None,
+            }
+            .into_any_element(),
+            None => self.render_group(None, cx),
+        }
+    }
+}
+
+impl ComponentExampleGroup {
+    /// Returns the id the group keeps its state under, if it needs one.
+    fn element_id(&self) -> Option<ElementId> {
+        self.id.clone().or_else(|| {
+            self.title
+                .clone()
+                .filter(|_| self.collapsible || self.scrollable)
+                .map(ElementId::Name)
+        })
+    }
+
+    /// Renders the group. `collapsed` is the group's element state, which is
+    /// only available when the group has an id.
+    fn render_group(self, collapsed: Option<CollapsedState>, cx: &mut App) -> AnyElement {
+        let scrolls = self.scrollable && collapsed.is_some();
+        let collapsed_state = collapsed.filter(|_| self.collapsible && self.title.is_some());
+        let collapsible = collapsed_state.is_some();
+        let collapsed = collapsed_state
+            .as_ref()
+            .is_some_and(|collapsed| collapsed.0.get());
+
         div()
             .flex_col()
             .text_sm()
             .text_color(cx.theme().colors().text_muted)
             .when(self.grow, |this| this.w_full().flex_1())
+            .when_some(self.max_width, |this, width| this.max_w(width))
+            .when_some(self.min_height, |this, height| this.min_h(height))
+            .when_some(self.max_height, |this, height| this.max_h(height))
+            .when(self.border, |this| {
+                this.p_2()
+                    .border_1()
+                    .border_color(cx.theme().colors().border)
+                    .rounded_md()
+            })
             .when_some(self.title, |this, title| {
-                this.gap_4().child(
-                    div()
-                        .flex()
-                        .items_center()
-                        .gap_3()
-                        .pb_1()
-                        .child(div().h_px().w_4().bg(cx.theme().colors().border))
-                        .child(
-                            div()
-                                .flex_none()
-                                .text_size(px(10.))
-                                .child(title.to_uppercase()),
-                        )
-                        .child(
-                            div()
-                                .h_px()
-                                .w_full()
-                                .flex_1()
-                                .bg(cx.theme().colors().border),
-                        ),
-                )
+                let header = div()
+                    .flex()
+                    .items_center()
+                    .gap_3()
+                    .pb_1()
+                    .child(div().h_px().w_4().bg(cx.theme().colors().border))
+                    .child(
+                        div()
+                            .flex_none()
+                            .text_size(px(10.))
+                            .child(title.to_uppercase()),
+                    )
+                    .when(collapsible, |this| {
+                        this.child(div().flex_none().text_size(px(10.)).child(if collapsed {
+                            "▶"
+                        } else {
+                            "▼"
+                        }))
+                    })
+                    .child(
+                        div()
+                            .h_px()
+                            .w_full()
+                            .flex_1()
+                            .bg(cx.theme().colors().border),
+                    );
+
+                let header = match collapsed_state {
+                    // The header's id is namespaced by the group's id.
+                    Some(collapsed) => header
+                        .id("header")
+                        .cursor_pointer()
+                        .on_click(move |_, window, _| {
+                            collapsed.0.set(!collapsed.0.get());
+                            window.refresh();
+                        })
+                        .into_any_element(),
+                    None => header.into_any_element(),
+                };
+
+                this.gap_4().child(header)
+            })
+            .when(!collapsed, |this| {
+                let gap = self.gap;
+                let scrollable = |container: Div, vertical: bool| {
+                    if !scrolls {
+                        return container.into_any_element();
+                    }
+                    // Namespaced by the group's id, like the header's.
+                    let container = container.id("examples");
+                    if self.grow {
+                        container.overflow_scroll().into_any_element()
+                    } else if vertical {
+                        container.overflow_y_scroll().into_any_element()
+                    } else {
+                        container.overflow_x_scroll().into_any_element()
+                    }
+                };
+                this.child(match self.columns.filter(|columns| *columns > 1) {
+                    Some(columns) => {
+                        let mut examples = self.examples.into_iter().peekable();
+                        let mut rows = Vec::new();
+                        while examples.peek().is_some() {
+                            rows.push(
+                                div()
+                                    .flex()
+                                    .items_start()
+                                    .w_full()
+                                    .map(|this| match gap {
+                                        Some(gap) => this.gap(gap),
+                                        None => this.gap_6(),
+                                    })
+                                    .children(examples.by_ref().take(columns)),
+                            );
+                        }
+                        div()
+                            .flex()
+                            .flex_col()
+                            .w_full()
+                            .map(|this| match gap {
+                                Some(gap) => this.gap(gap),
+                                None => this.gap_6(),
+                            })
+                            .children(rows)
+                            .map(|this| scrollable(this, true))
+                    }
+                    None => {
+                        let vertical = self.vertical || self.columns == Some(1);
+                        div()
+                            .flex()
+                            .when(vertical, |this| this.flex_col())
+                            .items_start()
+                            .w_full()
+                            .map(|this| match gap {
+                                Some(gap) => this.gap(gap),
+                                None => this.gap_6(),
+                            })
+                            .children(self.examples)
+                            .map(|this| scrollable(this, vertical))
+                    }
+                })
+                .when(!self.children.is_empty(), |this| {
+                    this.child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .w_full()
+                            .gap_4()
+                            .pl_4()
+                            .children(self.children),
+                    )
+                })
             })
-            .child(
-                div()
-                    .flex()
-                    .when(self.vertical, |this| this.flex_col())
-                    .items_start()
-                    .w_full()
-                    .gap_6()
-                    .children(self.examples)
-                    .into_any_element(),
-            )
             .into_any_element()
     }
 }
 
+/// Renders a [`ComponentExampleGroup`] under its id, with access to the
+/// group's element state.
+struct ExampleGroupElement {
+    id: ElementId,
+    group: Option<ComponentExampleGroup>,
+    element: Option<AnyElement>,
+}
+
+impl Element for ExampleGroupElement {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let collapsed = window.with_element_state(id.unwrap(), |collapsed, _| {
+            let collapsed: CollapsedState = collapsed.unwrap_or_default();
+            (collapsed.clone(), collapsed)
+        });
+        let mut element = self
+            .group
+            .take()
+            .expect("example group laid out twice")
+            .render_group(Some(collapsed), cx);
+        let layout_id = element.request_layout(window, cx);
+        self.element = Some(element);
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        if let Some(element) = self.element.as_mut() {
+            element.prepaint(window, cx);
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(element) = self.element.as_mut() {
+            element.paint(window, cx);
+        }
+    }
+}
+
+impl IntoElement for ExampleGroupElement {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
 impl ComponentExampleGroup {
     /// Create a new group of examples with the given title.
     pub fn new(examples: Vec<ComponentExample>) -> Self {
         Self {
-            title: None,
             examples,
-            grow: false,
-            vertical: false,
+            ..Default::default()
         }
     }
 
@@ -363,11 +2625,34 @@ impl ComponentExampleGroup {
         Self {
             title: Some(title.into()),
             examples,
-            grow: false,
-            vertical: false,
+            ..Default::default()
+        }
+    }
+
+    /// Create a new group of nested groups with the given title.
+    pub fn with_groups(title: impl Into<SharedString>, groups: Vec<ComponentExampleGroup>) -> Self {
+        Self {
+            title: Some(title.into()),
+            children: groups,
+            ..Default::default()
         }
     }
 
+    /// Add an example to the end of the group.
+    pub fn push_example(&mut self, example: ComponentExample) -> &mut Self {
+        self.examples.push(example);
+        self
+    }
+
+    /// Add a group to the end of the group's examples, laid out alongside
+    /// them. Unlike [`Self::with_groups`], which nests groups below the
+    /// examples, the group takes the place of an unlabeled example.
+    pub fn with_group(mut self, group: ComponentExampleGroup) -> Self {
+        self.examples
+            .push(ComponentExample::new("", group).label_side(ExampleLabelSide::None));
+        self
+    }
+
     /// Set the group to grow to fill the available horizontal space.
     pub fn grow(mut self) -> Self {
         self.grow = true;
@@ -379,12 +2664,82 @@ impl ComponentExampleGroup {
         self.vertical = true;
         self
     }
+
+    /// Allow the group to be collapsed by clicking its title. Groups without a
+    /// title can't be collapsed.
+    ///
+    /// The collapsed state is kept for as long as the group keeps being
+    /// rendered, under the group's [`Self::id`], or under its title if it has
+    /// no id. Sibling groups with the same title need distinct ids.
+    pub fn collapsible(mut self) -> Self {
+        self.collapsible = true;
+        self
+    }
+
+    /// Lay the group out in rows of at most `columns` examples, overriding
+    /// [`Self::vertical`]. One column lays the group out vertically, and zero
+    /// columns keeps the default layout.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Draw a border around the group, to separate it from the groups around
+    /// it when it has no title.
+    pub fn border(mut self) -> Self {
+        self.border = true;
+        self
+    }
+
+    /// Scroll the examples when they overflow the group, vertically if the
+    /// group is laid out vertically and horizontally otherwise. Groups that
+    /// [`Self::grow`] scroll in both directions, since their size is set by
+    /// their container.
+    ///
+    /// The scroll position is kept under the given id, which is set as the
+    /// group's [`Self::id`].
+    pub fn scrollable(mut self, id: impl Into<ElementId>) -> Self {
+        self.scrollable = true;
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Give the group an id, unique among the elements around it, to keep its
+    /// collapsed and scroll state under.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the gap between examples.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = Some(gap.into());
+        self
+    }
+
+    /// Limit how wide the group can grow.
+    pub fn max_width(mut self, width: Pixels) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Set the minimum height of the group.
+    pub fn min_height(mut self, height: Pixels) -> Self {
+        self.min_height = Some(height);
+        self
+    }
+
+    /// Limit how tall the group can grow.
+    pub fn max_height(mut self, height: Pixels) -> Self {
+        self.max_height = Some(height);
+        self
+    }
 }
 
 /// Create a single example
-pub fn single_example(
+pub fn single_example<E: IntoElement>(
     variant_name: impl Into<SharedString>,
-    example: AnyElement,
+    example: E,
 ) -> ComponentExample {
     ComponentExample::new(variant_name, example)
 }
@@ -401,3 +2756,798 @@ pub fn example_group_with_title(
 ) -> ComponentExampleGroup {
     ComponentExampleGroup::with_title(title, examples)
 }
+
+/// Renders the preview of every registered component in a scrollable list,
+/// each under its component name, grouped by scope. Components without a
+/// preview are shown as a card instead, see [`ComponentMetadata::render_card`].
+pub fn render_gallery(window: &mut Window, cx: &mut App) -> AnyElement {
+    let mut sections = Vec::new();
+    for (ScopeKey(scope), components) in components().group_by_scope() {
+        let title = match scope {
+            Some(scope) => scope.to_string(),
+            None => "Uncategorized".to_string(),
+        };
+        let mut section = div().flex().flex_col().gap_4().child(
+            div()
+                .text_lg()
+                .text_color(cx.theme().colors().text)
+                .child(title),
+        );
+        for component in &components {
+            if !component.has_preview() {
+                section = section.child(component.render_card(cx));
+                continue;
+            }
+
+            let show_labels = component.previews().len() > 1;
+            // Ids namespace the state of each preview's elements, so groups
+            // with the same title in different previews don't share it.
+            let mut entry = div().id(component.id().0).flex().flex_col().gap_2().child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().colors().text_muted)
+                    .child(component.short_name().to_string()),
+            );
+            for (label, preview) in component.previews() {
+                entry = entry
+                    .when(show_labels, |this| {
+                        this.child(
+                            div()
+                                .text_xs()
+                                .text_color(cx.theme().colors().text_muted)
+                                .child(*label),
+                        )
+                    })
+                    .child(
+                        div()
+                            .id(*label)
+                            .when_some(component.preview_size(), |this, (width, height)| {
+                                this.w(px(width)).h(px(height))
+                            })
+                            .child(component.render_safe(*preview, window, cx)),
+                    );
+            }
+            section = section.child(entry);
+        }
+        sections.push(section);
+    }
+
+    div()
+        .id("component-gallery")
+        .size_full()
+        .overflow_y_scroll()
+        .flex()
+        .flex_col()
+        .gap_8()
+        .p_4()
+        .children(sections)
+        .into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(name: &'static str, description: Option<&'static str>) -> ComponentMetadata {
+        let component = ComponentMetadata::new(name);
+        match description {
+            Some(description) => component.with_description(description),
+            None => component,
+        }
+    }
+
+    fn all_components(components: impl IntoIterator<Item = ComponentMetadata>) -> AllComponents {
+        let mut all_components = AllComponents::new();
+        for component in components {
+            all_components.insert(component.id(), component);
+        }
+        all_components
+    }
+
+    #[test]
+    fn test_search() {
+        let components = all_components([
+            component("Button", Some("A clickable element")),
+            component("IconButton", None),
+            component("Label", Some("Text with a button-like style")),
+        ]);
+
+        let matches = components.search_matches("  BUTTON ");
+        let names: Vec<_> = matches
+            .iter()
+            .map(|search_match| search_match.component.name())
+            .collect();
+        assert_eq!(names, ["Button", "IconButton", "Label"]);
+        assert_eq!(matches[0].kind, ComponentMatchKind::Name);
+        assert_eq!(matches[2].kind, ComponentMatchKind::Description);
+
+        assert_eq!(components.search("").len(), 3);
+        assert!(components.search("missing").is_empty());
+    }
+
+    #[test]
+    fn test_sorting_by_short_name() {
+        let components = all_components([
+            component("agent::ZedLogo", None),
+            component("ui::components::Avatar", None),
+            component("editor::button", None),
+            component("agent::Button", None),
+        ]);
+
+        let names: Vec<_> = components
+            .all_sorted()
+            .into_iter()
+            .map(|component| component.name())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "ui::components::Avatar",
+                "agent::Button",
+                "editor::button",
+                "agent::ZedLogo"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_search_keywords() {
+        let button = component("Button", None).with_keywords(["action", "CTA"]);
+        let components = all_components([button, component("Label", None)]);
+
+        let matches = components.search_matches("cta");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].component.name(), "Button");
+        assert_eq!(matches[0].kind, ComponentMatchKind::Keyword);
+
+        let names: Vec<_> = components
+            .fuzzy_search("actn")
+            .into_iter()
+            .map(|(c, _)| c.name())
+            .collect();
+        assert_eq!(names, ["Button"]);
+    }
+
+    #[test]
+    fn test_search_aliases() {
+        let select =
+            component("Select", Some("Pick one of several options")).with_aliases(["Dropdown"]);
+        let components = all_components([select, component("Label", None)]);
+
+        let matches = components.search_matches("dropdown");
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].component.name(), "Select");
+        assert_eq!(matches[0].kind, ComponentMatchKind::Alias);
+        assert_eq!(components.total_count(), 2);
+    }
+
+    #[test]
+    fn test_metadata_builders() {
+        let select = ComponentMetadata::new("Select")
+            .with_version("2")
+            .with_since("0.180")
+            .with_status(ComponentStatus::Beta)
+            .with_tags(["form", "Form", "input"])
+            .with_keywords(["choice"])
+            .with_aliases(["Dropdown", "dropdown"])
+            .with_related(["Label"])
+            .with_source_location(Location::caller());
+        assert_eq!(select.version().as_deref(), Some("2"));
+        assert_eq!(select.since().as_deref(), Some("0.180"));
+        assert_eq!(select.status(), ComponentStatus::Beta);
+        assert!(!select.is_deprecated());
+        assert_eq!(select.tags(), ["form", "input"]);
+        assert_eq!(select.keywords(), ["choice"]);
+        assert_eq!(select.aliases(), ["Dropdown"]);
+        assert_eq!(select.related, ["Label"]);
+        assert_eq!(select.source_location().map(Location::file), Some(file!()));
+
+        let deprecated = ComponentMetadata::new("OldSelect")
+            .with_deprecation_message("Use Select")
+            .with_status(ComponentStatus::Stable);
+        assert!(deprecated.is_deprecated());
+        assert_eq!(deprecated.status(), ComponentStatus::Deprecated);
+        assert_eq!(
+            deprecated.deprecation_message().as_deref(),
+            Some("Use Select")
+        );
+
+        let deprecated =
+            ComponentMetadata::new("OldLabel").with_status(ComponentStatus::Deprecated);
+        assert!(deprecated.is_deprecated());
+        assert_eq!(deprecated.deprecation_message(), None);
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let components = all_components([
+            component("Button", None),
+            component("ButtonLike", None),
+            component("Banner", Some("Shows a prominent button")),
+            component("Label", Some("Big text")),
+        ]);
+
+        let matches = components.fuzzy_search("button");
+        let names: Vec<_> = matches.iter().map(|(c, _)| c.name()).collect();
+        assert_eq!(names, ["Button", "ButtonLike", "Banner"]);
+        assert_eq!(matches[0].1, 1.0);
+        assert!(matches.iter().all(|(_, score)| (0.0..=1.0).contains(score)));
+
+        let names: Vec<_> = components
+            .fuzzy_search("btn")
+            .into_iter()
+            .map(|(c, _)| c.name())
+            .collect();
+        assert_eq!(names, ["Button", "ButtonLike", "Banner"]);
+
+        assert!(components.fuzzy_search("xyz").is_empty());
+
+        let components =
+            all_components([component("ButtonIcon", None), component("IconButton", None)]);
+        let names: Vec<_> = components
+            .fuzzy_search("icon")
+            .into_iter()
+            .map(|(c, _)| c.name())
+            .collect();
+        assert_eq!(names, ["IconButton", "ButtonIcon"]);
+    }
+
+    #[test]
+    fn test_registry_diff() {
+        let before = RegistrySnapshot {
+            names: vec!["Button".into(), "Label".into(), "Modal".into()],
+        };
+        let after = RegistrySnapshot {
+            names: vec![
+                "Button".into(),
+                "IconButton".into(),
+                "Label".into(),
+                "Tooltip".into(),
+            ],
+        };
+
+        assert_eq!(
+            RegistrySnapshot::diff(&before, &after),
+            RegistryDiff {
+                added: vec!["IconButton".into(), "Tooltip".into()],
+                removed: vec!["Modal".into()],
+            }
+        );
+        assert_eq!(
+            RegistrySnapshot::diff(&after, &after),
+            RegistryDiff::default()
+        );
+    }
+
+    #[test]
+    fn test_scope_ordering() {
+        let scopes = [
+            ComponentScope::Unknown("Charts".into()),
+            ComponentScope::VersionControl,
+            ComponentScope::Layout,
+            ComponentScope::Unknown("Alerts".into()),
+            ComponentScope::Editor,
+            ComponentScope::Input,
+        ]
+        .into_iter()
+        .collect::<std::collections::BTreeSet<_>>();
+
+        assert_eq!(
+            scopes.into_iter().collect::<Vec<_>>(),
+            [
+                ComponentScope::Layout,
+                ComponentScope::Input,
+                ComponentScope::Editor,
+                ComponentScope::VersionControl,
+                ComponentScope::Unknown("Alerts".into()),
+                ComponentScope::Unknown("Charts".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sorted_scopes() {
+        let mut components = Vec::new();
+        for (name, scope) in [
+            ("Modal", Some(ComponentScope::Layout)),
+            ("Panel", Some(ComponentScope::Layout)),
+            ("Chart", Some(ComponentScope::Unknown("Charts".into()))),
+            ("Button", None),
+            ("Editor", Some(ComponentScope::Editor)),
+            ("Alert", Some(ComponentScope::Unknown("Alerts".into()))),
+        ] {
+            let mut component = component(name, None);
+            component.scope = scope;
+            components.push(component);
+        }
+
+        assert_eq!(
+            all_components(components).sorted_scopes(),
+            [
+                Some(ComponentScope::Editor),
+                Some(ComponentScope::Layout),
+                Some(ComponentScope::Unknown("Alerts".into())),
+                Some(ComponentScope::Unknown("Charts".into())),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scope_from_type_path() {
+        assert_eq!(
+            ComponentScope::from_type_path("ui::layout::Panel"),
+            Some(ComponentScope::Layout)
+        );
+        assert_eq!(
+            ComponentScope::from_type_path("ui::Input::TextInput"),
+            Some(ComponentScope::Input)
+        );
+        assert_eq!(
+            ComponentScope::from_type_path("git_ui::version_control::Blame<ui::Label>"),
+            Some(ComponentScope::VersionControl)
+        );
+        assert_eq!(ComponentScope::from_type_path("ui::button::Button"), None);
+        assert_eq!(ComponentScope::from_type_path("Button"), None);
+    }
+
+    #[test]
+    fn test_short_name() {
+        assert_eq!(
+            ComponentId::new("ui::button::Button").short_name(),
+            "Button"
+        );
+        assert_eq!(
+            ComponentId::new("ui::list::List<ui::label::Label>").short_name(),
+            "List<ui::label::Label>"
+        );
+        assert_eq!(ComponentId::new("Button").short_name(), "Button");
+        assert_eq!(
+            component("ui::checkbox::Checkbox", None).short_name(),
+            "Checkbox"
+        );
+    }
+
+    #[test]
+    fn test_scope_from_module_path() {
+        assert_eq!(
+            ComponentScope::from_module_path("ui::layout::stack"),
+            ComponentScope::Layout
+        );
+        assert_eq!(
+            ComponentScope::from_module_path("git_ui::version_control"),
+            ComponentScope::VersionControl
+        );
+        assert_eq!(
+            ComponentScope::from_module_path("ui::components::charts"),
+            ComponentScope::Unknown("charts".into())
+        );
+    }
+
+    #[test]
+    fn test_scope_display_round_trip() {
+        for scope in ComponentScope::built_in() {
+            assert_eq!(&ComponentScope::from(scope.to_string().as_str()), scope);
+            assert_eq!(&ComponentScope::from(scope.to_string()), scope);
+        }
+    }
+
+    #[test]
+    fn test_scope_discriminants() {
+        for scope in ComponentScope::built_in() {
+            let discriminant = u8::from(scope.clone());
+            assert_eq!(&ComponentScope::try_from(discriminant).unwrap(), scope);
+        }
+
+        assert_eq!(u8::from(ComponentScope::Unknown("Charts".into())), 255);
+        assert!(ComponentScope::try_from(255u8).is_err());
+        assert!(ComponentScope::try_from(100u8).is_err());
+    }
+
+    #[test]
+    fn test_group_by_scope() {
+        let mut layout = component("Stack", None);
+        layout.scope = Some(ComponentScope::Layout);
+        let mut editor = component("Gutter", None);
+        editor.scope = Some(ComponentScope::Editor);
+        let mut custom = component("Chart", None);
+        custom.scope = Some(ComponentScope::Unknown("Charts".into()));
+        let components = all_components([layout, editor, custom, component("Thing", None)]);
+
+        let scopes: Vec<_> = components.group_by_scope().into_keys().collect();
+        assert_eq!(
+            scopes,
+            [
+                ScopeKey(Some(ComponentScope::Layout)),
+                ScopeKey(Some(ComponentScope::Editor)),
+                ScopeKey(Some(ComponentScope::Unknown("Charts".into()))),
+                ScopeKey(None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_random_component() {
+        assert!(AllComponents::new().random_component().is_none());
+
+        let components = all_components([component("Button", None), component("Label", None)]);
+        let name = components.random_component().unwrap().name();
+        assert!(matches!(name.as_ref(), "Button" | "Label"));
+    }
+
+    #[test]
+    fn test_documentation_coverage() {
+        fn preview(_: &mut Window, _: &mut App) -> AnyElement {
+            div().into_any_element()
+        }
+
+        assert_eq!(AllComponents::new().documentation_coverage(), 1.0);
+
+        let components = all_components([
+            component("Button", Some("A button")).with_preview("Default", preview),
+            component("Label", Some("A label")),
+            component("Icon", None).with_preview("Default", preview),
+            component("Divider", None).with_preview("Default", preview),
+        ]);
+        let undocumented: Vec<_> = components.undocumented().iter().map(|c| c.name()).collect();
+        assert_eq!(
+            undocumented,
+            [SharedString::from("Divider"), "Icon".into(), "Label".into()]
+        );
+        assert_eq!(components.documentation_coverage(), 0.25);
+    }
+
+    #[test]
+    fn test_filter_by_platform() {
+        let components = all_components([
+            component("Button", None),
+            component("TitleBar", None).with_platforms([Platform::MacOs]),
+            component("WindowControls", None).with_platforms([Platform::Linux, Platform::Windows]),
+        ]);
+
+        let mut names: Vec<_> = components
+            .filter_by_platform(Platform::Linux)
+            .iter()
+            .map(|c| c.name())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            [SharedString::from("Button"), "WindowControls".into()]
+        );
+    }
+
+    #[test]
+    fn test_missing_accessibility_labels() {
+        let components = all_components([
+            component("Button", None).with_accessibility_label("Button"),
+            component("Spinner", None),
+            component("Avatar", None),
+        ]);
+
+        let names: Vec<_> = components
+            .missing_accessibility_labels()
+            .iter()
+            .map(|c| c.name())
+            .collect();
+        assert_eq!(names, [SharedString::from("Avatar"), "Spinner".into()]);
+    }
+
+    #[test]
+    fn test_matches() {
+        let button = component("Button", Some("A clickable control"));
+        assert!(button.matches("butt"));
+        assert!(button.matches(" CLICKABLE "));
+        assert!(button.matches(""));
+        assert!(!button.matches("toggle"));
+    }
+
+    #[test]
+    fn test_metadata_eq() {
+        fn preview(_: &mut Window, _: &mut App) -> AnyElement {
+            div().into_any_element()
+        }
+
+        let button = component("Button", Some("A button"));
+        assert!(button == button.clone());
+        assert!(button != component("Button", Some("A clickable button")));
+        assert!(button != component("Button", Some("A button")).with_preview("Default", preview));
+        assert!(
+            button.clone().with_preview("Default", preview)
+                == component("Button", Some("A button")).with_preview("Default", preview)
+        );
+    }
+
+    #[test]
+    fn test_grouped_by_initial() {
+        let components = all_components([
+            component("button", None),
+            component("ui::avatar::Avatar", None),
+            component("Banner", None),
+            component("2D Chart", None),
+        ]);
+
+        let groups: Vec<_> = components
+            .grouped_by_initial()
+            .into_iter()
+            .map(|(initial, components)| {
+                let names: Vec<_> = components.iter().map(|c| c.name()).collect();
+                (initial, names)
+            })
+            .collect();
+        assert_eq!(
+            groups,
+            [
+                ('#', vec![SharedString::from("2D Chart")]),
+                ('A', vec!["ui::avatar::Avatar".into()]),
+                ('B', vec!["Banner".into(), "button".into()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_example_scale() {
+        let scale = |factor| ComponentExample::new("Default", div()).scale(factor).scale;
+        assert_eq!(scale(2.), Some(2.));
+        assert_eq!(scale(100.), Some(10.));
+        assert_eq!(scale(0.), Some(0.1));
+        assert_eq!(scale(-1.), Some(0.1));
+        assert_eq!(scale(f32::NAN), Some(0.1));
+        assert_eq!(scale(f32::INFINITY), Some(0.1));
+    }
+
+    #[test]
+    fn test_example_group_element_id() {
+        assert_eq!(example_group(Vec::new()).element_id(), None);
+        assert_eq!(
+            example_group_with_title("Sizes", Vec::new()).element_id(),
+            None
+        );
+        assert_eq!(
+            example_group(Vec::new())
+                .scrollable("button-states")
+                .element_id(),
+            Some(ElementId::Name("button-states".into()))
+        );
+        assert_eq!(
+            example_group_with_title("Sizes", Vec::new())
+                .scrollable("button-sizes")
+                .element_id(),
+            Some(ElementId::Name("button-sizes".into()))
+        );
+        assert_eq!(
+            example_group_with_title("Sizes", Vec::new())
+                .collapsible()
+                .element_id(),
+            Some(ElementId::Name("Sizes".into()))
+        );
+        assert_eq!(
+            example_group_with_title("Sizes", Vec::new())
+                .collapsible()
+                .id("button-sizes")
+                .element_id(),
+            Some(ElementId::Name("button-sizes".into()))
+        );
+    }
+
+    /// Serializes tests that use the global registry. Hold the guard for the
+    /// whole test.
+    static REGISTRY_LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock_registry() -> parking_lot::MutexGuard<'static, ()> {
+        let guard = REGISTRY_LOCK.lock();
+        reset_registry();
+        guard
+    }
+
+    fn noop_preview(_: &mut Window, _: &mut App) -> AnyElement {
+        div().into_any_element()
+    }
+
+    #[test]
+    fn test_register_component_dynamic() {
+        let _lock = lock_registry();
+
+        register_component_dynamic(
+            component("Button", Some("A button")).with_preview("Default", noop_preview),
+        );
+
+        let button = component_by_id(&ComponentId::new("Button")).unwrap();
+        assert_eq!(button.description(), Some("A button".into()));
+        assert!(button.has_preview());
+        assert!(COMPONENT_DATA.read().get_by_name("Button").is_some());
+        assert!(component_by_id(&ComponentId::new("Label")).is_none());
+        assert_eq!(COMPONENT_DATA.read().len(), 1);
+    }
+
+    #[test]
+    fn test_components_cache_invalidation() {
+        let _lock = lock_registry();
+
+        register_component_dynamic(component("Button", None));
+        assert_eq!(components().total_count(), 1);
+        assert!(Arc::ptr_eq(&components(), &components()));
+
+        let generation = registry_generation();
+        register_component_dynamic(component("Label", None));
+        assert_ne!(registry_generation(), generation);
+        assert_eq!(components().total_count(), 2);
+
+        deregister_component(&ComponentId::new("Button"));
+        let components = components();
+        assert_eq!(components.total_count(), 1);
+        assert!(!components.has_component(&ComponentId::new("Button")));
+    }
+
+    #[test]
+    fn test_deregister_component_drops_previews() {
+        let _lock = lock_registry();
+
+        register_component_dynamic(component("Button", None).with_preview("Default", noop_preview));
+        deregister_component(&ComponentId::new("Button"));
+        assert!(component_by_id(&ComponentId::new("Button")).is_none());
+
+        register_component_dynamic(component("Button", None));
+        let button = component_by_id(&ComponentId::new("Button")).unwrap();
+        assert!(!button.has_preview());
+    }
+
+    #[test]
+    fn test_duplicate_names() {
+        let _lock = lock_registry();
+
+        register_component_dynamic(component("Button", None));
+        register_component_dynamic(component("Label", None));
+        register_component_dynamic(component("Button", None));
+        register_component_dynamic(component("Button", None));
+
+        assert_eq!(duplicate_names(), [SharedString::from("Button")]);
+        assert_eq!(COMPONENT_DATA.read().len(), 2);
+    }
+
+    #[test]
+    fn test_on_register() {
+        let _lock = lock_registry();
+
+        let registered = Arc::new(Mutex::new(Vec::new()));
+        let subscription = on_register({
+            let registered = registered.clone();
+            move |id| registered.lock().push(id.clone())
+        });
+        register_component_dynamic(component("Button", None));
+        register_component::<TestLabel>();
+        assert_eq!(
+            *registered.lock(),
+            [ComponentId::new("Button"), ComponentId::new("TestLabel")]
+        );
+
+        drop(subscription);
+        register_component_dynamic(component("Label", None));
+        assert_eq!(registered.lock().len(), 2);
+    }
+
+    struct TestButton;
+
+    impl Component for TestButton {
+        fn name() -> &'static str {
+            "TestButton"
+        }
+
+        fn related() -> &'static [&'static str] {
+            &["TestLabel", "TestMissing"]
+        }
+    }
+
+    struct TestLabel;
+
+    impl Component for TestLabel {
+        fn name() -> &'static str {
+            "TestLabel"
+        }
+    }
+
+    #[test]
+    fn test_related() {
+        let _lock = lock_registry();
+
+        register_component::<TestButton>();
+        register_component::<TestLabel>();
+
+        let button = component_by_id(&ComponentId::new("TestButton")).unwrap();
+        assert_eq!(button.related(), [ComponentId::new("TestLabel")]);
+    }
+
+    fn init_test(cx: &mut gpui::TestAppContext) {
+        cx.update(|cx| {
+            let store = settings::SettingsStore::test(cx);
+            cx.set_global(store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+        });
+    }
+
+    fn panicking_preview(_: &mut Window, _: &mut App) -> AnyElement {
+        panic!("preview exploded")
+    }
+
+    #[gpui::test]
+    fn test_render_preview_safe_catches_panics(cx: &mut gpui::TestAppContext) {
+        let _lock = lock_registry();
+        init_test(cx);
+
+        register_component_dynamic(
+            component("Exploding", None).with_preview("Default", panicking_preview),
+        );
+        let exploding = component_by_id(&ComponentId::new("Exploding")).unwrap();
+
+        let cx = cx.add_empty_window();
+        cx.update(|window, cx| {
+            let result = render_preview_catching_panics(panicking_preview, window, cx);
+            assert_eq!(result.err().as_deref(), Some("preview exploded"));
+            assert!(!is_catching_preview_panics());
+        });
+
+        cx.draw(
+            gpui::point(px(0.), px(0.)),
+            gpui::size(px(600.), px(600.)),
+            |window, cx| exploding.render_preview_safe(window, cx),
+        );
+        assert!(cx.debug_bounds("component-preview-error").is_some());
+    }
+
+    fn empty_preview(_: &mut Window, _: &mut App) -> AnyElement {
+        div().into_any_element()
+    }
+
+    fn sized_preview(_: &mut Window, _: &mut App) -> AnyElement {
+        div().w(px(40.)).h(px(20.)).into_any_element()
+    }
+
+    #[gpui::test]
+    fn test_all_previews_reports_failures(cx: &mut gpui::TestAppContext) {
+        let _lock = lock_registry();
+        init_test(cx);
+
+        register_component_dynamic(component("Empty", None).with_preview("Default", empty_preview));
+        register_component_dynamic(
+            component("Exploding", None).with_preview("Default", panicking_preview),
+        );
+        register_component_dynamic(component("Sized", None).with_preview("Default", sized_preview));
+
+        let cx = cx.add_empty_window();
+        assert_eq!(
+            test_all_previews(cx),
+            [
+                (
+                    ComponentId::new("Empty"),
+                    "Default: rendered an empty element".to_string()
+                ),
+                (
+                    ComponentId::new("Exploding"),
+                    "Default: preview exploded".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[gpui::test]
+    fn test_cloned_example_group_rebuilds_examples(cx: &mut gpui::TestAppContext) {
+        init_test(cx);
+
+        let builds = Rc::new(Cell::new(0));
+        let group = example_group(vec![ComponentExample::from_fn("Default", {
+            let builds = builds.clone();
+            move |_, _| {
+                builds.set(builds.get() + 1);
+                div().w(px(40.)).h(px(20.))
+            }
+        })]);
+
+        let cx = cx.add_empty_window();
+        cx.draw(
+            gpui::point(px(0.), px(0.)),
+            gpui::size(px(600.), px(600.)),
+            |_, _| div().child(group.clone()).child(group),
+        );
+        assert_eq!(builds.get(), 2);
+    }
+}
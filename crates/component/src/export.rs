@@ -0,0 +1,172 @@
+//! Exports the component registry for tooling and documentation outside of
+//! Zed.
+
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::{AllComponents, ComponentId, ComponentMetadata, ComponentScope, components};
+
+/// Scopes are serialized to the same strings [`ComponentScope::from`] parses,
+/// so they round-trip.
+#[cfg(feature = "serde")]
+impl Serialize for ComponentScope {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ComponentScope::Unknown(name) => serializer.serialize_str(name),
+            scope => serializer.serialize_str(&scope.to_string()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ComponentScope {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let scope = String::deserialize(deserializer)?;
+        Ok(ComponentScope::from(scope))
+    }
+}
+
+/// Serializes everything but the preview, which can't be represented outside
+/// of Zed.
+#[cfg(feature = "serde")]
+impl Serialize for ComponentMetadata {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ComponentMetadata", 16)?;
+        state.serialize_field("id", &self.id)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("scope", &self.scope)?;
+        state.serialize_field("description", &self.description)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("since", &self.since)?;
+        state.serialize_field("deprecated", &self.deprecated)?;
+        state.serialize_field("deprecation_message", &self.deprecation_message)?;
+        state.serialize_field("status", &self.status)?;
+        state.serialize_field("tags", &self.tags)?;
+        state.serialize_field("keywords", &self.keywords)?;
+        state.serialize_field("aliases", &self.aliases)?;
+        state.serialize_field(
+            "source_location",
+            &self
+                .source_location
+                .map(|location| (location.file(), location.line())),
+        )?;
+        state.serialize_field("related", &self.related)?;
+        state.serialize_field("platforms", &self.platforms)?;
+        state.serialize_field("accessibility_label", &self.accessibility_label)?;
+        state.end()
+    }
+}
+
+/// Serializes the given components to a JSON array, sorted by name.
+#[cfg(feature = "serde")]
+pub fn export_json(components: &AllComponents) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&components.all_sorted())
+}
+
+/// An entry of [`catalog_json`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct CatalogEntry {
+    id: ComponentId,
+    name: String,
+    scope: Option<ComponentScope>,
+    description: Option<String>,
+    has_preview: bool,
+}
+
+#[cfg(feature = "serde")]
+impl CatalogEntry {
+    fn new(component: &ComponentMetadata) -> Self {
+        CatalogEntry {
+            id: component.id(),
+            name: component.name().to_string(),
+            scope: component.scope(),
+            description: component
+                .description()
+                .map(|description| description.to_string()),
+            has_preview: component.has_preview(),
+        }
+    }
+}
+
+/// Serializes the id, name, scope, description and whether there's a preview
+/// of every registered component to a JSON array, sorted by name.
+#[cfg(feature = "serde")]
+pub fn catalog_json() -> String {
+    let entries: Vec<CatalogEntry> = components()
+        .all_sorted()
+        .iter()
+        .map(CatalogEntry::new)
+        .collect();
+    serde_json::to_string_pretty(&entries).expect("catalog entries are always serializable")
+}
+
+/// Renders every registered component as a Markdown list under a heading for
+/// each scope, with components that don't belong to a scope last.
+#[cfg(feature = "serde")]
+pub fn catalog_markdown() -> String {
+    let components = components();
+    let mut groups = components.grouped_by_scope();
+    let mut markdown = String::new();
+    for scope in components.sorted_scopes() {
+        let Some(group) = groups.remove(&scope) else {
+            continue;
+        };
+        if !markdown.is_empty() {
+            markdown.push('\n');
+        }
+        let heading = match scope {
+            Some(scope) => scope.to_string(),
+            None => "Uncategorized".to_string(),
+        };
+        markdown.push_str(&format!("## {heading}\n\n"));
+        for component in group {
+            match component.description() {
+                Some(description) => {
+                    markdown.push_str(&format!("- **{}** — {description}\n", component.name()))
+                }
+                None => markdown.push_str(&format!("- **{}**\n", component.name())),
+            }
+        }
+    }
+    markdown
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_round_trip() {
+        let scopes = ComponentScope::built_in()
+            .iter()
+            .cloned()
+            .chain([ComponentScope::Unknown("Charts".into())]);
+        for scope in scopes {
+            let json = serde_json::to_string(&scope).unwrap();
+            let deserialized: ComponentScope = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, scope);
+        }
+
+        assert_eq!(
+            serde_json::to_string(&ComponentScope::VersionControl).unwrap(),
+            "\"Version Control\""
+        );
+    }
+
+    #[test]
+    fn test_exports_encode_scopes_alike() {
+        let component = ComponentMetadata::new("Chart")
+            .with_scope(ComponentScope::Unknown("Charts".into()))
+            .with_related(["Legend"]);
+
+        let metadata = serde_json::to_value(&component).unwrap();
+        let entry = serde_json::to_value(CatalogEntry::new(&component)).unwrap();
+        assert_eq!(metadata["scope"], "Charts");
+        assert_eq!(entry["scope"], metadata["scope"]);
+        assert_eq!(metadata["related"], serde_json::json!(["Legend"]));
+    }
+}
@@ -6,14 +6,12 @@ use std::iter::Iterator;
 use std::sync::Arc;
 
 use client::UserStore;
-use component::{ComponentId, ComponentMetadata, components};
+use component::{AllComponents, ComponentId, ComponentMetadata, components};
 use gpui::{
     App, Entity, EventEmitter, FocusHandle, Focusable, Task, WeakEntity, Window, list, prelude::*,
     uniform_list,
 };
 
-use collections::HashMap;
-
 use gpui::{ListState, ScrollHandle, UniformListScrollHandle};
 use languages::LanguageRegistry;
 use notifications::status_toast::{StatusToast, ToastIcon};
@@ -90,7 +88,7 @@ struct ComponentPreview {
     focus_handle: FocusHandle,
     _view_scroll_handle: ScrollHandle,
     nav_scroll_handle: UniformListScrollHandle,
-    component_map: HashMap<ComponentId, ComponentMetadata>,
+    component_map: Arc<AllComponents>,
     active_page: PreviewPage,
     components: Vec<ComponentMetadata>,
     component_list: ListState,
@@ -136,7 +134,7 @@ impl ComponentPreview {
             user_store,
             workspace,
             active_page: PreviewPage::AllComponents,
-            component_map: components().0,
+            component_map: components(),
             components: sorted_components,
             component_list,
             cursor_index: selected_index,
@@ -180,25 +178,27 @@ impl ComponentPreview {
         }
 
         for components in scope_groups.values_mut() {
-            components.sort_by_key(|c| c.name().to_lowercase());
+            components.sort_by_cached_key(|c| (c.short_name().to_lowercase(), c.name()));
         }
 
         let mut entries = Vec::new();
 
-        let known_scopes = [
+        // Always show all components first
+        entries.push(PreviewEntry::AllComponents);
+        entries.push(PreviewEntry::Separator);
+
+        // Keep the sidebar's existing order, which lists Editor before
+        // Notification, and list any other built-in scopes after these.
+        let leading_scopes = [
             ComponentScope::Layout,
             ComponentScope::Input,
             ComponentScope::Editor,
             ComponentScope::Notification,
-            ComponentScope::Collaboration,
-            ComponentScope::VersionControl,
         ];
-
-        // Always show all components first
-        entries.push(PreviewEntry::AllComponents);
-        entries.push(PreviewEntry::Separator);
-
-        for scope in known_scopes.iter() {
+        let other_scopes = ComponentScope::built_in()
+            .iter()
+            .filter(|scope| !leading_scopes.contains(scope));
+        for scope in leading_scopes.iter().chain(other_scopes) {
             let scope_key = Some(scope.clone());
             if let Some(components) = scope_groups.remove(&scope_key) {
                 if !components.is_empty() {
@@ -250,7 +250,10 @@ impl ComponentPreview {
                 let id = component_metadata.id();
                 let selected = self.active_page == PreviewPage::Component(id.clone());
                 ListItem::new(ix)
-                    .child(Label::new(component_metadata.name().clone()).color(Color::Default))
+                    .child(
+                        Label::new(component_metadata.short_name().to_string())
+                            .color(Color::Default),
+                    )
                     .selectable(true)
                     .toggle_state(selected)
                     .inset(true)
@@ -333,12 +336,13 @@ impl ComponentPreview {
         window: &mut Window,
         cx: &mut App,
     ) -> impl IntoElement {
-        let name = component.name();
+        let name = component.short_name().to_string();
         let scope = component.scope();
 
         let description = component.description();
 
         v_flex()
+            .id(component.id().0)
             .py_2()
             .child(
                 v_flex()
@@ -19,6 +19,7 @@ mod schema;
 mod settings;
 mod styles;
 
+use std::cell::RefCell;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -130,10 +131,57 @@ pub trait ActiveTheme {
 
 impl ActiveTheme for App {
     fn theme(&self) -> &Arc<Theme> {
-        &ThemeSettings::get_global(self).active_theme
+        theme_override(self).unwrap_or_else(|| &ThemeSettings::get_global(self).active_theme)
     }
 }
 
+thread_local! {
+    /// Themes temporarily replacing the active theme of an app, innermost
+    /// last. Each theme is boxed so references to it stay valid as the stack
+    /// grows.
+    static THEME_OVERRIDES: RefCell<Vec<(*const App, Box<Arc<Theme>>)>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+/// Returns the innermost theme overriding the active theme of the given app.
+fn theme_override(cx: &App) -> Option<&Arc<Theme>> {
+    THEME_OVERRIDES.with_borrow(|overrides| {
+        let (_, theme) = overrides
+            .iter()
+            .rev()
+            .find(|(app, _)| std::ptr::eq(*app, cx))?;
+        let theme: *const Arc<Theme> = &**theme;
+        // SAFETY: The theme is only removed when the `ThemeOverrideGuard` of
+        // the `with_theme_override` call that added it is dropped. That call
+        // holds `&mut App` of this app until then, so no reference borrowed
+        // from `cx` can still be alive.
+        Some(unsafe { &*theme })
+    })
+}
+
+/// Removes the innermost theme override when dropped, so it's removed even if
+/// the function given to [`with_theme_override`] panics.
+struct ThemeOverrideGuard;
+
+impl Drop for ThemeOverrideGuard {
+    fn drop(&mut self) {
+        THEME_OVERRIDES.with_borrow_mut(|overrides| overrides.pop());
+    }
+}
+
+/// Runs the given function with [`ActiveTheme::theme`] returning the given
+/// theme instead of the active theme, without changing the theme settings.
+///
+/// This is meant for rendering part of the UI in another theme, like a
+/// preview of a component in both light and dark themes.
+pub fn with_theme_override<R>(theme: Arc<Theme>, cx: &mut App, f: impl FnOnce(&mut App) -> R) -> R {
+    THEME_OVERRIDES.with_borrow_mut(|overrides| {
+        overrides.push((cx as *const App, Box::new(theme)));
+    });
+    let _guard = ThemeOverrideGuard;
+    f(cx)
+}
+
 /// A theme family is a grouping of themes under a single name.
 ///
 /// For example, the "One" theme family contains the "One Light" and "One Dark" themes.
@@ -381,3 +429,42 @@ pub async fn read_icon_theme(
 
     Ok(icon_theme_family)
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+    use settings::SettingsStore;
+
+    use super::*;
+
+    #[gpui::test]
+    fn test_with_theme_override(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings_store = SettingsStore::test(cx);
+            cx.set_global(settings_store);
+            init(LoadThemes::JustBase, cx);
+
+            let active_theme = cx.theme().clone();
+            let overriding_theme = Arc::new(Theme {
+                name: "Overriding Theme".into(),
+                ..active_theme.as_ref().clone()
+            });
+
+            let inner_name = with_theme_override(overriding_theme.clone(), cx, |cx| {
+                assert_eq!(cx.theme().name, overriding_theme.name);
+                with_theme_override(active_theme.clone(), cx, |cx| cx.theme().name.clone())
+            });
+            assert_eq!(inner_name, active_theme.name);
+
+            assert!(THEME_OVERRIDES.with_borrow(|overrides| overrides.is_empty()));
+            assert!(Arc::ptr_eq(cx.theme(), &active_theme));
+
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                with_theme_override(overriding_theme.clone(), cx, |_| panic!("preview failed"))
+            }));
+            assert!(result.is_err());
+            assert!(THEME_OVERRIDES.with_borrow(|overrides| overrides.is_empty()));
+            assert!(Arc::ptr_eq(cx.theme(), &active_theme));
+        });
+    }
+}
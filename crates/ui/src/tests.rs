@@ -1 +1,2 @@
+mod derive_component;
 mod path_str;
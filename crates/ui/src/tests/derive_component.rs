@@ -0,0 +1,44 @@
+// We need to test [ui_macros::IntoComponent] here as we can't invoke it
+// in the `ui_macros` crate.
+#[cfg(test)]
+mod tests {
+    use component::{Component, ComponentScope};
+
+    mod layout {
+        use component::ComponentPreview;
+        use gpui::{AnyElement, App, IntoElement, Window, div};
+        use ui_macros::IntoComponent;
+
+        #[derive(IntoComponent)]
+        pub struct InferredStack;
+
+        impl ComponentPreview for InferredStack {
+            fn preview(_window: &mut Window, _cx: &mut App) -> AnyElement {
+                div().into_any_element()
+            }
+        }
+
+        #[derive(IntoComponent)]
+        #[component(scope = "input")]
+        pub struct AnnotatedStack;
+
+        impl ComponentPreview for AnnotatedStack {
+            fn preview(_window: &mut Window, _cx: &mut App) -> AnyElement {
+                div().into_any_element()
+            }
+        }
+    }
+
+    #[test]
+    fn test_derive_component_infers_scope() {
+        assert_eq!(layout::InferredStack::scope(), Some(ComponentScope::Layout));
+    }
+
+    #[test]
+    fn test_derive_component_with_scope() {
+        assert_eq!(
+            layout::AnnotatedStack::scope(),
+            Some(ComponentScope::from("input"))
+        );
+    }
+}
@@ -7,6 +7,10 @@ pub fn derive_into_component(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let mut scope_val = None;
     let mut description_val = None;
+    let mut version_val = None;
+    let mut since_val = None;
+    let mut deprecated_val = None;
+    let mut tags_val = None;
 
     for attr in &input.attrs {
         if attr.path.is_ident("component") {
@@ -19,10 +23,14 @@ pub fn derive_into_component(input: TokenStream) -> TokenStream {
                     })) = item
                     {
                         let ident = path.get_ident().map(|i| i.to_string()).unwrap_or_default();
-                        if ident == "scope" {
-                            scope_val = Some(s.value());
-                        } else if ident == "description" {
-                            description_val = Some(s.value());
+                        match ident.as_str() {
+                            "scope" => scope_val = Some(s.value()),
+                            "description" => description_val = Some(s.value()),
+                            "version" => version_val = Some(s.value()),
+                            "since" => since_val = Some(s.value()),
+                            "deprecated" => deprecated_val = Some(s.value()),
+                            "tags" => tags_val = Some(s.value()),
+                            _ => {}
                         }
                     }
                 }
@@ -32,6 +40,8 @@ pub fn derive_into_component(input: TokenStream) -> TokenStream {
 
     let name = &input.ident;
 
+    // Without a scope attribute, `Component::scope` infers the scope from the
+    // module the component is defined in.
     let scope_impl = if let Some(s) = scope_val {
         let scope_str = s.clone();
         quote! {
@@ -40,17 +50,19 @@ pub fn derive_into_component(input: TokenStream) -> TokenStream {
             }
         }
     } else {
-        quote! {
-            fn scope() -> Option<component::ComponentScope> {
-                None
-            }
-        }
+        quote! {}
     };
 
-    let description_impl = if let Some(desc) = description_val {
+    let description_impl = optional_str_method("description", description_val);
+    let version_impl = optional_str_method("version", version_val);
+    let since_impl = optional_str_method("since", since_val);
+    let deprecated_impl = optional_str_method("deprecation_message", deprecated_val);
+
+    let tags_impl = if let Some(tags) = tags_val {
+        let tags = tags.split(',').map(str::trim).filter(|tag| !tag.is_empty());
         quote! {
-            fn description() -> Option<&'static str> {
-                Some(#desc)
+            fn tags() -> &'static [&'static str] {
+                &[#(#tags),*]
             }
         }
     } else {
@@ -77,10 +89,18 @@ pub fn derive_into_component(input: TokenStream) -> TokenStream {
             #scope_impl
 
             fn name() -> &'static str {
-                stringify!(#name)
+                concat!(module_path!(), "::", stringify!(#name))
             }
 
             #description_impl
+            #version_impl
+            #since_impl
+            #deprecated_impl
+            #tags_impl
+
+            fn source_location() -> Option<&'static std::panic::Location<'static>> {
+                Some(std::panic::Location::caller())
+            }
         }
 
         #[linkme::distributed_slice(component::__ALL_COMPONENTS)]
@@ -96,3 +116,16 @@ pub fn derive_into_component(input: TokenStream) -> TokenStream {
 
     expanded.into()
 }
+
+fn optional_str_method(method: &str, value: Option<String>) -> proc_macro2::TokenStream {
+    if let Some(value) = value {
+        let method = syn::Ident::new(method, proc_macro2::Span::call_site());
+        quote! {
+            fn #method() -> Option<&'static str> {
+                Some(#value)
+            }
+        }
+    } else {
+        quote! {}
+    }
+}
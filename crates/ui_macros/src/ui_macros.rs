@@ -67,8 +67,13 @@ pub fn derive_dynamic_spacing(input: TokenStream) -> TokenStream {
 ///
 /// # Attributes
 ///
-/// - `#[component(scope = "...")]`: Required. Specifies the scope of the component.
+/// - `#[component(scope = "...")]`: Optional. Specifies the scope of the component. Without it, the scope is
+///   inferred from the module the component is defined in.
 /// - `#[component(description = "...")]`: Optional. Provides a description for the component.
+/// - `#[component(version = "...")]`: Optional. The current API version of the component.
+/// - `#[component(since = "...")]`: Optional. The version the component was introduced in.
+/// - `#[component(deprecated = "...")]`: Optional. Marks the component as deprecated with the given message.
+/// - `#[component(tags = "...")]`: Optional. A comma-separated list of tags for the component.
 ///
 /// # Example
 ///
@@ -33,6 +33,15 @@ pub fn init_panic_hook(
     let is_pty = stdout_is_a_pty();
 
     panic::set_hook(Box::new(move |info| {
+        if component::is_catching_preview_panics() {
+            // Component previews catch panics in their preview functions and
+            // render an error in their place, so let this one unwind instead
+            // of exiting. Panics while drawing a preview's element aren't
+            // caught, and don't get here.
+            log::error!("component preview panicked: {info}");
+            return;
+        }
+
         let prior_panic_count = PANIC_COUNT.fetch_add(1, Ordering::SeqCst);
         if prior_panic_count > 0 {
             // Give the panic-ing thread time to write the panic file